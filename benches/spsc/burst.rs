@@ -23,7 +23,7 @@ const BURST_SIZE: usize = 512;
 
 #[bench]
 fn veloce_lamport(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BURST_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -36,7 +36,7 @@ fn veloce_lamport(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BURST_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -51,7 +51,7 @@ fn veloce_vyukov(b: &mut Bencher) {
 /// This is the ideal use case for drain: single-threaded batch processing.
 #[bench]
 fn veloce_lamport_drain(b: &mut Bencher) {
-    let (tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BURST_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -66,7 +66,7 @@ fn veloce_lamport_drain(b: &mut Bencher) {
 /// This is the ideal use case for drain: single-threaded batch processing.
 #[bench]
 fn veloce_vyukov_drain(b: &mut Bencher) {
-    let (tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BURST_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -80,7 +80,7 @@ fn veloce_vyukov_drain(b: &mut Bencher) {
 /// Drain with larger batch to show scalability.
 #[bench]
 fn veloce_lamport_drain_full(b: &mut Bencher) {
-    let (tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         // Fill entire buffer
         for i in 0..BUFFER_SIZE {
@@ -96,7 +96,7 @@ fn veloce_lamport_drain_full(b: &mut Bencher) {
 /// Drain with larger batch to show scalability.
 #[bench]
 fn veloce_vyukov_drain_full(b: &mut Bencher) {
-    let (tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         // Fill entire buffer
         for i in 0..BUFFER_SIZE {
@@ -109,6 +109,42 @@ fn veloce_vyukov_drain_full(b: &mut Bencher) {
     });
 }
 
+/// Sends and receives through the futures, which never wait here: nobody is registered,
+/// so the notifications are skipped.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_lamport_async(b: &mut Bencher) {
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    b.iter(|| {
+        futures::executor::block_on(async {
+            for i in 0..BURST_SIZE {
+                tx.send(i as i32).await.unwrap();
+            }
+            for _ in 0..BURST_SIZE {
+                test::black_box(rx.recv().await.unwrap());
+            }
+        })
+    });
+}
+
+/// Sends and receives through the futures, which never wait here: nobody is registered,
+/// so the notifications are skipped.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_vyukov_async(b: &mut Bencher) {
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    b.iter(|| {
+        futures::executor::block_on(async {
+            for i in 0..BURST_SIZE {
+                tx.send(i as i32).await.unwrap();
+            }
+            for _ in 0..BURST_SIZE {
+                test::black_box(rx.recv().await.unwrap());
+            }
+        })
+    });
+}
+
 #[bench]
 fn crossbeam(b: &mut Bencher) {
     let (tx, rx) = crossbeam_bounded::<i32>(BUFFER_SIZE);
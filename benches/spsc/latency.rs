@@ -19,12 +19,14 @@ use kanal::bounded as kanal_bounded;
 use test::Bencher;
 use veloce::spsc::lamport::channel as lamport_channel;
 use veloce::spsc::vyukov::channel as vyukov_channel;
+#[cfg(feature = "async")]
+use veloce::spsc::wait::Spin;
 const PING_PONG_ROUNDS: usize = 10_000;
 
 #[bench]
 fn veloce_lamport(b: &mut Bencher) {
-    let (tx1, rx1) = lamport_channel::<i32, 2>();
-    let (tx2, rx2) = lamport_channel::<i32, 2>();
+    let (mut tx1, mut rx1) = lamport_channel::<i32, 2>();
+    let (mut tx2, mut rx2) = lamport_channel::<i32, 2>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -58,8 +60,8 @@ fn veloce_lamport(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov(b: &mut Bencher) {
-    let (tx1, rx1) = vyukov_channel::<i32, 2>();
-    let (tx2, rx2) = vyukov_channel::<i32, 2>();
+    let (mut tx1, mut rx1) = vyukov_channel::<i32, 2>();
+    let (mut tx2, mut rx2) = vyukov_channel::<i32, 2>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -91,6 +93,118 @@ fn veloce_vyukov(b: &mut Bencher) {
     .unwrap();
 }
 
+/// Both threads drive futures with `block_on`: without spinning, every round trip goes through
+/// the wakers and parks the threads.
+#[cfg(feature = "async")]
+fn veloce_lamport_futures(b: &mut Bencher, spin: Spin) {
+    use futures::executor::block_on;
+
+    let (mut tx1, mut rx1) = lamport_channel::<i32, 2>();
+    let (mut tx2, mut rx2) = lamport_channel::<i32, 2>();
+
+    let (start_tx, start_rx) = crossbeam_bounded(0);
+    let (done_tx, done_rx) = crossbeam_bounded(0);
+
+    scope(|s| {
+        // Pong thread
+        s.spawn(|_| {
+            while start_rx.recv().is_ok() {
+                block_on(async {
+                    for _ in 0..PING_PONG_ROUNDS {
+                        let v = rx1.recv().with_spin(spin).await.unwrap();
+                        tx2.send(v).await.unwrap();
+                    }
+                });
+                done_tx.send(()).unwrap();
+            }
+        });
+
+        // Ping thread (benchmark thread)
+        b.iter(|| {
+            start_tx.send(()).unwrap();
+            block_on(async {
+                for i in 0..PING_PONG_ROUNDS {
+                    tx1.send(i as i32).await.unwrap();
+                    test::black_box(rx2.recv().with_spin(spin).await.unwrap());
+                }
+            });
+            done_rx.recv().unwrap();
+        });
+
+        drop(start_tx);
+    })
+    .unwrap();
+}
+
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_lamport_async(b: &mut Bencher) {
+    veloce_lamport_futures(b, Spin::Never);
+}
+
+/// Spins before yielding, to get close to `recv_spin` while still using futures.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_lamport_async_spin(b: &mut Bencher) {
+    veloce_lamport_futures(b, Spin::For(std::time::Duration::from_micros(50)));
+}
+
+/// Both threads drive futures with `block_on`: without spinning, every round trip goes through
+/// the wakers and parks the threads.
+#[cfg(feature = "async")]
+fn veloce_vyukov_futures(b: &mut Bencher, spin: Spin) {
+    use futures::executor::block_on;
+
+    let (mut tx1, mut rx1) = vyukov_channel::<i32, 2>();
+    let (mut tx2, mut rx2) = vyukov_channel::<i32, 2>();
+
+    let (start_tx, start_rx) = crossbeam_bounded(0);
+    let (done_tx, done_rx) = crossbeam_bounded(0);
+
+    scope(|s| {
+        // Pong thread
+        s.spawn(|_| {
+            while start_rx.recv().is_ok() {
+                block_on(async {
+                    for _ in 0..PING_PONG_ROUNDS {
+                        let v = rx1.recv().with_spin(spin).await.unwrap();
+                        tx2.send(v).await.unwrap();
+                    }
+                });
+                done_tx.send(()).unwrap();
+            }
+        });
+
+        // Ping thread (benchmark thread)
+        b.iter(|| {
+            start_tx.send(()).unwrap();
+            block_on(async {
+                for i in 0..PING_PONG_ROUNDS {
+                    tx1.send(i as i32).await.unwrap();
+                    test::black_box(rx2.recv().with_spin(spin).await.unwrap());
+                }
+            });
+            done_rx.recv().unwrap();
+        });
+
+        drop(start_tx);
+    })
+    .unwrap();
+}
+
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_vyukov_async(b: &mut Bencher) {
+    veloce_vyukov_futures(b, Spin::Never);
+}
+
+/// Spins before yielding, to get close to `recv_spin` while still using futures.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_vyukov_async_spin(b: &mut Bencher) {
+    veloce_vyukov_futures(b, Spin::For(std::time::Duration::from_micros(50)));
+}
+
 #[bench]
 fn crossbeam(b: &mut Bencher) {
     let (tx1, rx1) = crossbeam_bounded::<i32>(2);
@@ -19,7 +19,7 @@ const BUFFER_SIZE: usize = 1024;
 #[bench]
 fn veloce_lamport(b: &mut Bencher) {
     b.iter(|| {
-        let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+        let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
         tx.try_send(42).unwrap();
         rx.try_recv().unwrap()
     });
@@ -28,7 +28,7 @@ fn veloce_lamport(b: &mut Bencher) {
 #[bench]
 fn veloce_vyukov(b: &mut Bencher) {
     b.iter(|| {
-        let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+        let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
         tx.try_send(42).unwrap();
         rx.try_recv().unwrap()
     });
@@ -22,7 +22,7 @@ const BATCH_SIZE: usize = 64;
 
 #[bench]
 fn veloce_lamport(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         tx.try_send(42).unwrap();
         rx.try_recv().unwrap()
@@ -31,7 +31,7 @@ fn veloce_lamport(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         tx.try_send(42).unwrap();
         rx.try_recv().unwrap()
@@ -42,7 +42,7 @@ fn veloce_vyukov(b: &mut Bencher) {
 /// Dividing the result by BATCH_SIZE gives per-item overhead.
 #[bench]
 fn veloce_lamport_batch(b: &mut Bencher) {
-    let (tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BATCH_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -57,7 +57,7 @@ fn veloce_lamport_batch(b: &mut Bencher) {
 /// Dividing the result by BATCH_SIZE gives per-item overhead.
 #[bench]
 fn veloce_vyukov_batch(b: &mut Bencher) {
-    let (tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
     b.iter(|| {
         for i in 0..BATCH_SIZE {
             tx.try_send(i as i32).unwrap();
@@ -35,7 +35,7 @@ fn work(v: i32) -> i32 {
 
 #[bench]
 fn veloce_lamport_spin(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -66,7 +66,7 @@ fn veloce_lamport_spin(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov_spin(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -97,7 +97,7 @@ fn veloce_vyukov_spin(b: &mut Bencher) {
 
 #[bench]
 fn veloce_lamport_drain(b: &mut Bencher) {
-    let (tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -138,7 +138,7 @@ fn veloce_lamport_drain(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov_drain(b: &mut Bencher) {
-    let (tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -24,7 +24,7 @@ const DRAIN_BATCH: usize = 32;
 
 #[bench]
 fn veloce_lamport_spin(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, SMALL_BUFFER>();
+    let (mut tx, mut rx) = lamport_channel::<i32, SMALL_BUFFER>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -54,7 +54,7 @@ fn veloce_lamport_spin(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov_spin(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, SMALL_BUFFER>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, SMALL_BUFFER>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -86,7 +86,7 @@ fn veloce_vyukov_spin(b: &mut Bencher) {
 /// Expected to be slower than spin due to backpressure.
 #[bench]
 fn veloce_lamport_drain(b: &mut Bencher) {
-    let (tx, mut rx) = lamport_channel::<i32, SMALL_BUFFER>();
+    let (mut tx, mut rx) = lamport_channel::<i32, SMALL_BUFFER>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -129,7 +129,7 @@ fn veloce_lamport_drain(b: &mut Bencher) {
 /// Expected to be slower than spin due to backpressure.
 #[bench]
 fn veloce_vyukov_drain(b: &mut Bencher) {
-    let (tx, mut rx) = vyukov_channel::<i32, SMALL_BUFFER>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, SMALL_BUFFER>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -24,7 +24,7 @@ pub const TOTAL_MESSAGES: usize = 100_000;
 
 #[bench]
 fn veloce_lamport_spin(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -55,7 +55,7 @@ fn veloce_lamport_spin(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov_spin(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -86,7 +86,7 @@ fn veloce_vyukov_spin(b: &mut Bencher) {
 
 #[bench]
 fn veloce_lamport_try(b: &mut Bencher) {
-    let (tx, rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -131,7 +131,7 @@ fn veloce_lamport_try(b: &mut Bencher) {
 
 #[bench]
 fn veloce_vyukov_try(b: &mut Bencher) {
-    let (tx, rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -184,7 +184,7 @@ fn veloce_vyukov_try(b: &mut Bencher) {
 fn veloce_lamport_drain(b: &mut Bencher) {
     const DRAIN_BATCH: usize = 256;
 
-    let (tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -232,7 +232,7 @@ fn veloce_lamport_drain(b: &mut Bencher) {
 fn veloce_vyukov_drain(b: &mut Bencher) {
     const DRAIN_BATCH: usize = 256;
 
-    let (tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
 
     let (start_tx, start_rx) = crossbeam_bounded(0);
     let (done_tx, done_rx) = crossbeam_bounded(0);
@@ -270,6 +270,86 @@ fn veloce_vyukov_drain(b: &mut Bencher) {
     .unwrap();
 }
 
+/// Both halves are futures, driven by `block_on` on their thread. A task only registers its
+/// waker when the channel is empty (or full): successful sends and receives find nobody
+/// to wake, and skip the notification.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_lamport_async(b: &mut Bencher) {
+    use futures::executor::block_on;
+
+    let (mut tx, mut rx) = lamport_channel::<i32, BUFFER_SIZE>();
+
+    let (start_tx, start_rx) = crossbeam_bounded(0);
+    let (done_tx, done_rx) = crossbeam_bounded(0);
+
+    scope(|s| {
+        s.spawn(|_| {
+            while start_rx.recv().is_ok() {
+                block_on(async {
+                    for i in 0..TOTAL_MESSAGES {
+                        tx.send(i as i32).await.unwrap();
+                    }
+                });
+                done_tx.send(()).unwrap();
+            }
+        });
+
+        b.iter(|| {
+            start_tx.send(()).unwrap();
+            block_on(async {
+                for _ in 0..TOTAL_MESSAGES {
+                    rx.recv().await.unwrap();
+                }
+            });
+            done_rx.recv().unwrap();
+        });
+
+        drop(start_tx);
+    })
+    .unwrap();
+}
+
+/// Both halves are futures, driven by `block_on` on their thread. A task only registers its
+/// waker when the channel is empty (or full): successful sends and receives find nobody
+/// to wake, and skip the notification.
+#[cfg(feature = "async")]
+#[bench]
+fn veloce_vyukov_async(b: &mut Bencher) {
+    use futures::executor::block_on;
+
+    let (mut tx, mut rx) = vyukov_channel::<i32, BUFFER_SIZE>();
+
+    let (start_tx, start_rx) = crossbeam_bounded(0);
+    let (done_tx, done_rx) = crossbeam_bounded(0);
+
+    scope(|s| {
+        s.spawn(|_| {
+            while start_rx.recv().is_ok() {
+                block_on(async {
+                    for i in 0..TOTAL_MESSAGES {
+                        tx.send(i as i32).await.unwrap();
+                    }
+                });
+                done_tx.send(()).unwrap();
+            }
+        });
+
+        b.iter(|| {
+            start_tx.send(()).unwrap();
+            block_on(async {
+                for _ in 0..TOTAL_MESSAGES {
+                    rx.recv().await.unwrap();
+                }
+            });
+            done_rx.recv().unwrap();
+        });
+
+        drop(start_tx);
+    })
+    .unwrap();
+}
+
 #[bench]
 fn crossbeam(b: &mut Bencher) {
     let (tx, rx) = crossbeam_bounded::<i32>(BUFFER_SIZE);
@@ -1,5 +1,6 @@
 pub(crate) mod ring;
+pub(crate) mod sync;
 
-pub use ring::Storable;
+pub use ring::{DYNAMIC, Storable};
 
 pub mod spsc;
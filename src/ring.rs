@@ -1,4 +1,6 @@
-use std::{cell::UnsafeCell, mem::MaybeUninit, ptr};
+use std::{mem::MaybeUninit, ptr};
+
+use crate::sync::UnsafeCell;
 
 #[allow(clippy::missing_safety_doc)]
 pub trait Storable {
@@ -8,9 +10,36 @@ pub trait Storable {
     unsafe fn drop_in_place(&self);
 }
 
+/// Slot of the channels, whose accesses are tracked by loom under `cfg(loom)`.
 impl<T> Storable for UnsafeCell<MaybeUninit<T>> {
     type Item = T;
 
+    /// # Safety:
+    ///
+    /// this will overwrite any existing value without dropping it, causing a potential memory leak
+    #[inline]
+    unsafe fn write(&self, value: T) {
+        self.with_mut(|slot| unsafe { ptr::write((*slot).as_mut_ptr(), value) });
+    }
+
+    #[inline]
+    unsafe fn read(&self) -> Self::Item {
+        self.with(|slot| unsafe { ptr::read((*slot).as_ptr()) })
+    }
+
+    /// # Safety:
+    ///
+    /// the slot expects intialized data
+    #[inline]
+    unsafe fn drop_in_place(&self) {
+        self.with_mut(|slot| unsafe { ptr::drop_in_place((*slot).as_mut_ptr()) });
+    }
+}
+
+#[cfg(not(loom))]
+impl<T> Storable for std::cell::UnsafeCell<MaybeUninit<T>> {
+    type Item = T;
+
     /// # Safety:
     ///
     /// this will overwrite any existing value without dropping it, causing a potential memory leak
@@ -35,36 +64,176 @@ impl<T> Storable for UnsafeCell<MaybeUninit<T>> {
     }
 }
 
+/// Number of steps from `head` to `tail`, for sequences wrapping at `wrap`.
+///
+/// A `wrap` of `0` stands for sequences wrapping at `usize::MAX + 1`, where this is just a `wrapping_sub`.
+#[inline]
+pub(crate) fn distance(head: usize, tail: usize, wrap: usize) -> usize {
+    let d = tail.wrapping_sub(head);
+    if tail < head { d.wrapping_add(wrap) } else { d }
+}
+
+/// Capacity marker for buffers sized at runtime.
+///
+/// A `RingBuffer<T, DYNAMIC>` keeps its slots on the heap and computes its index mask
+/// at construction, while any other `N` keeps them inline with a compile-time mask.
+pub const DYNAMIC: usize = 0;
+
 /// # Safety:
 ///
 /// RingBuffer doesn't have any implementation of [`Drop`]: it is responsibility of the
 /// wrapper of [`RingBuffer`] monitoring which memory slots have been initialized and would require drop
-pub(crate) struct RingBuffer<T, const N: usize>([T; N])
+///
+/// # Indexing
+///
+/// With a power of 2 capacity, sequences are unbounded (they wrap at `usize::MAX + 1`) and are
+/// mapped to slots with a mask. Buffers built with [`RingBuffer::exact`] accept any capacity:
+/// sequences then live in `[0, 2 * capacity)` and are mapped to slots with a conditional
+/// subtraction, so that `head == tail` (empty) and `tail - head == capacity` (full) stay distinct.
+pub(crate) struct RingBuffer<T, const N: usize>
 where
-    T: Storable;
+    T: Storable,
+{
+    /// Slots of a buffer sized at compile time (`N != DYNAMIC`)
+    inline: [T; N],
+    /// Slots of a buffer sized at runtime (`N == DYNAMIC`)
+    heap: Box<[T]>,
+    /// `capacity - 1` of a buffer sized at runtime
+    mask: usize,
+    /// Value at which sequences of a buffer sized at runtime wrap, `0` for power of 2 capacities
+    wrap: usize,
+}
 
 impl<T: Storable, const N: usize> From<[T; N]> for RingBuffer<T, N> {
     fn from(buffer: [T; N]) -> Self {
         assert!(Self::N_POWER_OF_2, "N must be power of 2");
         assert!(Self::N_POSITIVE, "N must be positive");
-        Self(buffer)
+        Self {
+            inline: buffer,
+            heap: Box::new([]),
+            mask: Self::MODULO_MASK,
+            wrap: Self::WRAP,
+        }
+    }
+}
+
+impl<T: Storable> From<Box<[T]>> for RingBuffer<T, DYNAMIC> {
+    fn from(buffer: Box<[T]>) -> Self {
+        let capacity = buffer.len();
+        assert!(capacity.is_power_of_two(), "capacity must be power of 2");
+        Self {
+            inline: [],
+            heap: buffer,
+            mask: capacity - 1,
+            wrap: 0,
+        }
+    }
+}
+
+impl<T: Storable> RingBuffer<T, DYNAMIC> {
+    /// Like [`RingBuffer::exact`], for a buffer sized at runtime.
+    pub(crate) fn exact_boxed(buffer: Box<[T]>) -> Self {
+        let capacity = buffer.len();
+        assert!(capacity > 0, "capacity must be positive");
+        if capacity.is_power_of_two() {
+            return buffer.into();
+        }
+        Self {
+            inline: [],
+            heap: buffer,
+            mask: capacity - 1,
+            wrap: 2 * capacity,
+        }
     }
 }
 
 impl<T: Storable, const N: usize> RingBuffer<T, N> {
-    const MODULO_MASK: usize = N - 1;
+    const MODULO_MASK: usize = N.wrapping_sub(1);
     const N_POWER_OF_2: bool = N.is_power_of_two();
     const N_POSITIVE: bool = N > 0;
+    const WRAP: usize = if Self::N_POWER_OF_2 { 0 } else { 2 * N };
+
+    /// Builds a buffer of any positive capacity.
+    ///
+    /// Power of 2 capacities keep the mask, the others are indexed by conditional subtraction.
+    pub(crate) fn exact(buffer: [T; N]) -> Self {
+        assert!(Self::N_POSITIVE, "N must be positive");
+        Self {
+            inline: buffer,
+            heap: Box::new([]),
+            mask: Self::MODULO_MASK,
+            wrap: Self::WRAP,
+        }
+    }
+
+    /// The slots of the buffer.
+    ///
+    /// The branch is resolved at compile time, so statically sized buffers don't pay for it.
+    #[inline]
+    fn slots(&self) -> &[T] {
+        if N == DYNAMIC {
+            &self.heap
+        } else {
+            &self.inline
+        }
+    }
+
+    /// Returns the number of slots.
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        if N == DYNAMIC { self.heap.len() } else { N }
+    }
 
     /// Returns a reference to the value at the given index.
     #[inline]
     pub(crate) fn get(&self, index: usize) -> &T {
-        &self.0[index]
+        &self.slots()[index]
     }
 
+    /// Value at which sequences wrap, `0` meaning `usize::MAX + 1`.
+    ///
+    /// Resolved at compile time for statically sized buffers.
     #[inline]
-    pub(crate) const fn index(&self, seq: usize) -> usize {
-        seq & Self::MODULO_MASK
+    pub(crate) fn wrap(&self) -> usize {
+        if N == DYNAMIC { self.wrap } else { Self::WRAP }
+    }
+
+    /// Maps a sequence to the index of its slot.
+    #[inline]
+    pub(crate) fn index(&self, seq: usize) -> usize {
+        if self.wrap() != 0 {
+            // seq < 2 * capacity
+            let capacity = self.capacity();
+            if seq >= capacity { seq - capacity } else { seq }
+        } else if N == DYNAMIC {
+            seq & self.mask
+        } else {
+            seq & Self::MODULO_MASK
+        }
+    }
+
+    /// Returns the sequence `n` steps after `seq`, with `n <= capacity`.
+    #[inline]
+    pub(crate) fn advance(&self, seq: usize, n: usize) -> usize {
+        let seq = seq.wrapping_add(n);
+        let wrap = self.wrap();
+        if wrap != 0 && seq >= wrap {
+            seq - wrap
+        } else {
+            seq
+        }
+    }
+
+    /// Returns the sequence following `seq`.
+    #[inline]
+    pub(crate) fn next(&self, seq: usize) -> usize {
+        self.advance(seq, 1)
+    }
+
+    /// Returns the number of steps from `head` to `tail`.
+    #[inline]
+    pub(crate) fn distance(&self, head: usize, tail: usize) -> usize {
+        distance(head, tail, self.wrap())
     }
 
     /// # Safety
@@ -72,7 +241,7 @@ impl<T: Storable, const N: usize> RingBuffer<T, N> {
     /// - `i` is assumed to be an index of the inner slice
     #[inline]
     pub(crate) unsafe fn write(&self, i: usize, value: T::Item) {
-        let cell = &self.0[i];
+        let cell = self.get(i);
         unsafe { cell.write(value) };
     }
 
@@ -81,7 +250,7 @@ impl<T: Storable, const N: usize> RingBuffer<T, N> {
     /// - `i` is assumed to be an index of the inner slice
     #[inline]
     pub(crate) unsafe fn read(&self, i: usize) -> T::Item {
-        let cell = &self.0[i];
+        let cell = self.get(i);
         unsafe { cell.read() }
     }
 
@@ -90,19 +259,46 @@ impl<T: Storable, const N: usize> RingBuffer<T, N> {
     /// - `i` is assumed to be an index of the inner slice
     #[inline]
     pub(crate) unsafe fn drop_in_place(&self, i: usize) {
-        let cell = &self.0[i];
+        let cell = self.get(i);
         unsafe { cell.drop_in_place() }
     }
 }
 
 impl<T, const N: usize> Default for RingBuffer<UnsafeCell<MaybeUninit<T>>, N> {
     fn default() -> Self {
-        let buffer = [const { UnsafeCell::new(MaybeUninit::uninit()) }; N];
+        let buffer = std::array::from_fn(|_| UnsafeCell::new(MaybeUninit::uninit()));
         buffer.into()
     }
 }
 
-#[cfg(test)]
+impl<T, const N: usize> RingBuffer<UnsafeCell<MaybeUninit<T>>, N> {
+    /// Like [`Default::default`], but accepts any positive `N`.
+    pub(crate) fn default_exact() -> Self {
+        Self::exact(std::array::from_fn(|_| {
+            UnsafeCell::new(MaybeUninit::uninit())
+        }))
+    }
+}
+
+impl<T> RingBuffer<UnsafeCell<MaybeUninit<T>>, DYNAMIC> {
+    /// Allocates a buffer with `capacity` slots, which must be a power of two.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self::uninit_slots(capacity).into()
+    }
+
+    /// Allocates a buffer with any positive number of slots.
+    pub(crate) fn with_exact_capacity(capacity: usize) -> Self {
+        Self::exact_boxed(Self::uninit_slots(capacity))
+    }
+
+    fn uninit_slots(capacity: usize) -> Box<[UnsafeCell<MaybeUninit<T>>]> {
+        (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect()
+    }
+}
+
+#[cfg(all(test, not(loom)))]
 mod ring_test {
     use super::*;
 
@@ -121,6 +317,19 @@ mod ring_test {
         };
     }
 
+    /// The exported trait is implemented for the std cell
+    #[test]
+    fn test_std_storable() {
+        let cell = std::cell::UnsafeCell::new(MaybeUninit::uninit());
+        unsafe {
+            Storable::write(&cell, String::from("veloce"));
+            assert_eq!(Storable::read(&cell), "veloce");
+
+            Storable::write(&cell, String::from("dropped"));
+            Storable::drop_in_place(&cell);
+        }
+    }
+
     /// Ring buffer should not be constructed with N that is not power of two
     #[test]
     #[should_panic]
@@ -152,4 +361,61 @@ mod ring_test {
         let i = 6;
         assert_ne!(i & RF::MODULO_MASK, i % N_F);
     }
+
+    /// A buffer sized at runtime behaves like a buffer sized at compile time
+    #[test]
+    fn test_dynamic() {
+        let ring = RingBuffer::<UnsafeCell<MaybeUninit<_>>, DYNAMIC>::with_capacity(4);
+        assert_eq!(ring.capacity(), 4);
+        assert_eq!(ring.index(5), 1);
+
+        let i = ring.index(7);
+        unsafe {
+            ring.write(i, 28392);
+            assert_eq!(ring.read(i), 28392)
+        };
+    }
+
+    /// Sequences of an exact buffer wrap at twice its capacity
+    #[test]
+    fn test_exact() {
+        const N: usize = 3;
+        let ring = RingBuffer::<UnsafeCell<MaybeUninit<()>>, N>::default_exact();
+        assert_eq!(ring.wrap(), 2 * N);
+
+        let mut seq = 0;
+        for step in 0..4 * N {
+            assert_eq!(ring.index(seq), step % N);
+            seq = ring.next(seq);
+        }
+        assert_eq!(seq, 0);
+
+        assert_eq!(ring.advance(5, N), 2);
+        assert_eq!(ring.distance(4, 1), 3);
+        assert_eq!(ring.distance(1, 4), 3);
+        assert_eq!(ring.distance(2, 2), 0);
+
+        let ring = RingBuffer::<UnsafeCell<MaybeUninit<()>>, DYNAMIC>::with_exact_capacity(1000);
+        assert_eq!(ring.capacity(), 1000);
+        assert_eq!(ring.index(1999), 999);
+        assert_eq!(ring.next(1999), 0);
+        assert_eq!(ring.distance(1990, 10), 20);
+
+        // power of 2 capacities keep the mask
+        let ring = RingBuffer::<UnsafeCell<MaybeUninit<()>>, DYNAMIC>::with_exact_capacity(4);
+        assert_eq!(ring.wrap(), 0);
+        assert_eq!(ring.next(usize::MAX), 0);
+    }
+
+    /// Runtime capacity must be a power of two as well
+    #[test]
+    #[should_panic]
+    fn test_dynamic_panics() {
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+
+        RingBuffer::<UnsafeCell<MaybeUninit<()>>, DYNAMIC>::with_capacity(6);
+
+        std::panic::set_hook(prev);
+    }
 }
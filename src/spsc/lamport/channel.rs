@@ -1,21 +1,17 @@
-use std::{
-    cell::UnsafeCell,
-    mem::MaybeUninit,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, AtomicUsize, Ordering},
-    },
+use crate::sync::{
+    Arc, UnsafeCell,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
 };
+use std::{mem::MaybeUninit, time::Instant};
 
 use crossbeam_utils::CachePadded;
 
 use crate::{
-    ring::RingBuffer,
+    ring::{DYNAMIC, RingBuffer},
     spsc::lamport::{receiver::Receiver, sender::Sender},
 };
 
-#[cfg(feature = "async")]
-use crate::spsc::r#async::Wakers;
+use crate::spsc::waiters::Waiters;
 #[cfg(feature = "async")]
 use std::task::Waker;
 
@@ -24,31 +20,44 @@ pub(super) struct Channel<T, const N: usize> {
     pub(super) head: CachePadded<AtomicUsize>,
     pub(super) tail: CachePadded<AtomicUsize>,
     pub(super) closed: CachePadded<AtomicBool>,
-
-    #[cfg(feature = "async")]
-    wakers: Wakers,
+    waiters: Waiters,
 }
 
 impl<T, const N: usize> Default for Channel<T, N> {
     fn default() -> Self {
-        let buffer = RingBuffer::default();
+        Self::new(RingBuffer::default())
+    }
+}
+
+impl<T> Channel<T, DYNAMIC> {
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        Self::new(RingBuffer::<UnsafeCell<MaybeUninit<T>>, DYNAMIC>::with_capacity(capacity))
+    }
+
+    pub(super) fn with_exact_capacity(capacity: usize) -> Self {
+        Self::new(RingBuffer::<UnsafeCell<MaybeUninit<T>>, DYNAMIC>::with_exact_capacity(capacity))
+    }
+}
+
+impl<T, const N: usize> Channel<T, N> {
+    pub(super) fn default_exact() -> Self {
+        Self::new(RingBuffer::<UnsafeCell<MaybeUninit<T>>, N>::default_exact())
+    }
+
+    fn new(buffer: RingBuffer<UnsafeCell<MaybeUninit<T>>, N>) -> Self {
         let closed = CachePadded::new(AtomicBool::new(false));
         let head = CachePadded::new(AtomicUsize::new(0));
         let tail = CachePadded::new(AtomicUsize::new(0));
-        #[cfg(feature = "async")]
-        let wakers = Wakers::default();
+        let waiters = Waiters::default();
         Self {
             buffer,
             closed,
             head,
             tail,
-            #[cfg(feature = "async")]
-            wakers,
+            waiters,
         }
     }
-}
 
-impl<T, const N: usize> Channel<T, N> {
     pub fn split(self) -> (Sender<T, N>, Receiver<T, N>) {
         let inner = Arc::new(self);
         let tx = Sender::new(inner.clone());
@@ -56,29 +65,66 @@ impl<T, const N: usize> Channel<T, N> {
         (tx, rx)
     }
 
+    /// Acquire: pairs with the Release store on drop, so that everything the dropped
+    /// half did before dropping is visible once `closed` is observed
     #[inline]
     pub fn is_closed(&self) -> bool {
-        self.closed.load(Ordering::Relaxed)
+        self.closed.load(Ordering::Acquire)
+    }
+
+    pub(super) fn park_sender(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_sender(deadline, ready);
+    }
+
+    pub(super) fn park_receiver(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_receiver(deadline, ready);
+    }
+
+    /// Unparks or wakes the sender, if it waits: see [`Waiters`].
+    pub(super) fn notify_sender(&self) {
+        self.waiters.notify_sender();
+    }
+
+    /// Unparks or wakes the receiver, if it waits: see [`Waiters`].
+    pub(super) fn notify_receiver(&self) {
+        self.waiters.notify_receiver();
+    }
+
+    /// Unparks or wakes the sender, if it waits for `free(watermark)` slots.
+    #[inline]
+    pub(super) fn notify_sender_at(&self, free: impl FnOnce(usize) -> bool) {
+        self.waiters.notify_sender_at(free);
+    }
+
+    /// Unparks or wakes the receiver, if it waits for `buffered(watermark)` values.
+    #[inline]
+    pub(super) fn notify_receiver_at(&self, buffered: impl FnOnce(usize) -> bool) {
+        self.waiters.notify_receiver_at(buffered);
+    }
+
+    #[cfg(feature = "async")]
+    pub(super) fn set_sender_watermark(&self, low: usize) {
+        self.waiters.set_sender_watermark(low);
     }
 
     #[cfg(feature = "async")]
-    pub(super) fn wake_sender(&self) {
-        self.wakers.wake_sender()
+    pub(super) fn set_receiver_watermark(&self, high: usize) {
+        self.waiters.set_receiver_watermark(high);
     }
 
     #[cfg(feature = "async")]
-    pub(super) fn wake_receiver(&self) {
-        self.wakers.wake_receiver()
+    pub(super) fn set_receiver_lingered(&self, lingered: bool) {
+        self.waiters.set_receiver_lingered(lingered);
     }
 
     #[cfg(feature = "async")]
     pub(super) fn register_sender_waker(&self, waker: &Waker) {
-        self.wakers.register_sender_waker(waker);
+        self.waiters.register_sender_waker(waker);
     }
 
     #[cfg(feature = "async")]
     pub(super) fn register_receiver_waker(&self, waker: &Waker) {
-        self.wakers.register_receiver_waker(waker);
+        self.waiters.register_receiver_waker(waker);
     }
 }
 
@@ -88,16 +134,14 @@ unsafe impl<T: Send, const N: usize> Send for Channel<T, N> {}
 // The channel is dropped when both Sender and Receiver have dropped
 impl<T, const N: usize> Drop for Channel<T, N> {
     fn drop(&mut self) {
-        // Safe using `get_mut` because:
-        // 1. Arc's acquire fence synchronized with all Release stores
-        // 2. &mut self guarantees exclusive access
-        // 3. No atomic operation needed - just reading memory we own exclusively
-        let head = *self.head.get_mut();
-        let tail = *self.tail.get_mut();
-        let count = tail.wrapping_sub(head);
+        // Relaxed is fine here: we have exclusive access (&mut self) and
+        // Arc's drop synchronized with all previous Release stores
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Relaxed);
+        let count = self.buffer.distance(head, tail);
         for s in 0..count {
             unsafe {
-                let i = self.buffer.index(head.wrapping_add(s));
+                let i = self.buffer.index(self.buffer.advance(head, s));
                 // Safe: these slots are initialized (producer wrote, consumer didn't read)
                 self.buffer.drop_in_place(i);
             }
@@ -1,18 +1,46 @@
+#[cfg(feature = "async")]
+use crate::spsc::SpscAsyncReceiver;
+use crate::spsc::SpscReceiver;
+use crate::sync::{Arc, atomic::Ordering, hint};
 use std::{
     cell::Cell,
     marker::PhantomData,
-    sync::{Arc, atomic::Ordering},
+    ops::{Deref, DerefMut},
+    ptr,
+    time::{Duration, Instant},
+};
+#[cfg(feature = "async")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
 };
 
-use crate::spsc::TryRecvError;
+use crate::spsc::{
+    RecvTimeoutError, TryRecvError,
+    wait::{Wait, WaitStrategy},
+};
 
 use super::{Cursors, channel::Channel};
+#[cfg(feature = "async")]
+use crate::spsc::{
+    coop::Budget,
+    timer::{ThreadSleep, ThreadTimer, Timer},
+};
 
 #[cfg(feature = "async")]
-pub use r#async::RecvFuture;
+pub use r#async::{RecvFuture, RecvManyFuture, RecvOwned, RecvTimeout};
 pub struct Receiver<T, const N: usize> {
     pub(super) inner: Arc<Channel<T, N>>,
     _not_clone: PhantomData<Cell<()>>, //marker type to avoid cloning implementations
+    /// Values handed out in a row by the futures, to yield to the executor once in a while.
+    #[cfg(feature = "async")]
+    budget: Budget,
+    /// See [`set_high_watermark()`](Self::set_high_watermark).
+    #[cfg(feature = "async")]
+    linger: Option<Duration>,
+    /// Wakes the task once it waited for the linger, armed on its first wait.
+    #[cfg(feature = "async")]
+    linger_sleep: Option<ThreadSleep>,
 }
 
 impl<T, const N: usize> Receiver<T, N> {
@@ -20,33 +48,55 @@ impl<T, const N: usize> Receiver<T, N> {
         Self {
             inner,
             _not_clone: PhantomData,
+            #[cfg(feature = "async")]
+            budget: Budget::default(),
+            #[cfg(feature = "async")]
+            linger: None,
+            #[cfg(feature = "async")]
+            linger_sleep: None,
         }
     }
 
     /// Consumer consumes a value from the buffer if it's ready
-    pub fn try_recv(&self) -> Result<T, TryRecvError> {
-        let cursors = self.cursors();
-
-        if cursors.is_empty() {
-            // Disconnection check happens only when we are sure that there are no more messages to read
-            if self.is_closed() {
-                return Err(TryRecvError::Disconnected);
-            }
-
-            return Err(TryRecvError::Empty);
-        }
-
-        let head = cursors.head;
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let head = self.ready()?;
 
         // Maps the head to the ring-buffer index and read the value
         let out = unsafe { self.read(head) };
 
-        // release-store: make sure that acquire-loads see also the previous readings on the buffer
-        self.inner.head.store(head + 1, Ordering::Release);
+        self.release(head);
 
         Ok(out)
     }
 
+    /// Borrows the next value in place, without moving it out of the buffer.
+    ///
+    /// The returned [`ReadGuard`] derefs to the value inside the ring slot. When the guard
+    /// drops, the value is dropped in place and the slot is handed back to the producer.
+    /// The `&mut self` borrow prevents concurrent access to the receiver until then.
+    ///
+    /// Prefer it to [`try_recv()`](Self::try_recv) for large messages that are inspected
+    /// and discarded: nothing is copied out of the slot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<[u64; 512], 4>();
+    /// tx.try_send([7; 512]).unwrap();
+    ///
+    /// let msg = rx.recv_ref().unwrap();
+    /// assert_eq!(msg[0], 7);
+    /// drop(msg); // the slot is freed here
+    ///
+    /// assert!(rx.recv_ref().is_err());
+    /// ```
+    pub fn recv_ref(&mut self) -> Result<ReadGuard<'_, T, N>, TryRecvError> {
+        let head = self.ready()?;
+        Ok(ReadGuard { rx: self, head })
+    }
+
     /// Receiver retrieves a new value from the buffer using a busy-spin strategy.
     ///
     /// If new value is not ready, it hints to the CPU that it is in a spin-wait
@@ -55,18 +105,87 @@ impl<T, const N: usize> Receiver<T, N> {
     ///
     /// This favors minimal latency over fairness, and avoids `thread::yield_now`,
     /// which may enter the scheduler and potentially deschedule the thread.
-    pub fn recv_spin(&self) -> Result<T, TryRecvError> {
+    pub fn recv_spin(&mut self) -> Result<T, TryRecvError> {
+        loop {
+            match self.try_recv() {
+                Ok(v) => return Ok(v),
+                Err(TryRecvError::Empty) => {
+                    hint::spin_loop();
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receiver retrieves a new value from the buffer, letting `strategy` decide how to idle
+    /// while the channel is empty.
+    ///
+    /// When the strategy asks to [`Park`](Wait::Park), the thread is unparked by the sender as
+    /// soon as it pushes a value (see [`recv_blocking`](Self::recv_blocking)).
+    pub fn recv_with<W: WaitStrategy>(&mut self, strategy: &mut W) -> Result<T, TryRecvError> {
+        strategy.reset();
+        loop {
+            match self.try_recv() {
+                Ok(v) => return Ok(v),
+                Err(TryRecvError::Empty) => {
+                    if strategy.wait() == Wait::Park {
+                        self.inner
+                            .park_receiver(None, || self.is_closed() || !self.is_empty());
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receiver retrieves a new value from the buffer, parking the thread while the channel is empty.
+    ///
+    /// The thread is unparked by the sender as soon as it pushes a value, whichever the
+    /// operation, or when the sender drops.
+    ///
+    /// This trades latency for CPU time: prefer [`recv_spin`](Self::recv_spin) when the
+    /// sender is expected to be busy.
+    pub fn recv_blocking(&mut self) -> Result<T, TryRecvError> {
         loop {
             match self.try_recv() {
                 Ok(v) => return Ok(v),
                 Err(TryRecvError::Empty) => {
-                    std::hint::spin_loop();
+                    self.inner
+                        .park_receiver(None, || self.is_closed() || !self.is_empty());
                 }
                 Err(e) => return Err(e),
             }
         }
     }
 
+    /// Like [`recv_blocking`](Self::recv_blocking), but gives up once `timeout` has elapsed.
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.recv_deadline(deadline),
+            // Too far in the future to be represented: wait forever
+            None => self
+                .recv_blocking()
+                .map_err(|_| RecvTimeoutError::Disconnected),
+        }
+    }
+
+    /// Like [`recv_blocking`](Self::recv_blocking), but gives up once `deadline` is reached.
+    pub fn recv_deadline(&mut self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        loop {
+            match self.try_recv() {
+                Ok(v) => return Ok(v),
+                Err(TryRecvError::Empty) => {
+                    if Instant::now() >= deadline {
+                        return Err(RecvTimeoutError::Timeout);
+                    }
+                    self.inner
+                        .park_receiver(Some(deadline), || self.is_closed() || !self.is_empty());
+                }
+                Err(TryRecvError::Disconnected) => return Err(RecvTimeoutError::Disconnected),
+            }
+        }
+    }
+
     /// Receiver retrieves a new value from the buffer using a async strategy.
     ///
     /// - On success: wakes the sender (if blocked on a full buffer) to signal
@@ -79,13 +198,349 @@ impl<T, const N: usize> Receiver<T, N> {
     ///
     /// This future is cancel-safe. Dropping it before completion does not lose data.
     #[cfg(feature = "async")]
-    pub fn recv(&self) -> RecvFuture<'_, T, N> {
+    pub fn recv(&mut self) -> RecvFuture<'_, T, N> {
         RecvFuture::new(self)
     }
 
-    /// Returns the channel capacity.
-    pub const fn capacity(&self) -> usize {
+    /// Like [`recv()`](Self::recv), but gives up once `timeout` has elapsed.
+    ///
+    /// The deadline is tracked by a [`ThreadTimer`](crate::spsc::timer::ThreadTimer), which
+    /// works on any executor; another [`Timer`](crate::spsc::timer::Timer) can be plugged in
+    /// with [`RecvTimeout::with_timer()`].
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe. Dropping it before completion does not lose data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use veloce::spsc::{RecvTimeoutError, lamport::channel};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 4>();
+    /// tx.try_send(1).unwrap();
+    /// assert_eq!(rx.recv_timeout_async(Duration::from_millis(10)).await.unwrap(), 1);
+    ///
+    /// let timeout = rx.recv_timeout_async(Duration::from_millis(10)).await;
+    /// assert!(matches!(timeout, Err(RecvTimeoutError::Timeout)));
+    ///
+    /// drop(tx);
+    /// let disconnected = rx.recv_timeout_async(Duration::from_millis(10)).await;
+    /// assert!(matches!(disconnected, Err(RecvTimeoutError::Disconnected)));
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn recv_timeout_async(&mut self, timeout: Duration) -> RecvTimeout<'_, T, N> {
+        RecvTimeout::new(self, timeout)
+    }
+
+    /// Like [`recv()`](Self::recv), but the future owns the receiver and hands it back along
+    /// with the result.
+    ///
+    /// [`RecvOwned`] doesn't borrow anything, so it is `'static` (if `T` is): it can be boxed,
+    /// spawned, or stored in a `FuturesUnordered` that outlives the receiver borrow.
+    ///
+    /// # Cancel Safety
+    ///
+    /// No value is lost, but dropping the future before completion drops the receiver,
+    /// which disconnects the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::stream::{FuturesUnordered, StreamExt};
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, rx) = channel::<u32, 4>();
+    /// let mut pending = FuturesUnordered::new();
+    /// pending.push(rx.recv_owned());
+    ///
+    /// tx.try_send(1).unwrap();
+    /// let (rx, value) = pending.next().await.unwrap();
+    /// assert_eq!(value.unwrap(), 1);
+    ///
+    /// // Queue the next receive
+    /// pending.push(rx.recv_owned());
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn recv_owned(self) -> RecvOwned<T, N> {
+        RecvOwned::new(self)
+    }
+
+    /// Polls for the next value, registering the receiver waker while the channel is empty.
+    ///
+    /// This is what [`recv()`](Self::recv) does on each poll, exposed for hand-written futures
+    /// and state machines that can't hold a [`RecvFuture`]:
+    /// - `Poll::Ready(Ok(value))`: a value has been received, and the sender woken up
+    /// - `Poll::Pending`: `cx` is woken once a value is sent or the sender drops
+    /// - `Poll::Ready(Err(TryRecvError::Disconnected))`: the sender dropped and the channel is drained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::task::{Context, Poll, Waker};
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<i32, 4>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert!(rx.poll_recv(&mut cx).is_pending());
+    ///
+    /// tx.try_send(1).unwrap();
+    /// assert!(matches!(rx.poll_recv(&mut cx), Poll::Ready(Ok(1))));
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, TryRecvError>> {
+        match self.try_recv() {
+            // The sender, who might be waiting for some free space in the buffer, has been notified
+            Ok(v) => {
+                self.reset_linger();
+                Poll::Ready(Ok(v))
+            }
+            Err(TryRecvError::Empty) => {
+                self.poll_linger(cx);
+                // we store the waker for future polls
+                self.inner.register_receiver_waker(cx.waker());
+
+                // We give a second shot to see if we should be woken up immediately
+                // Check if producer pushed some data or dropped in the meanwhile
+                if !self.is_empty() || self.is_closed() {
+                    // New data is available, self-wake to try another recv attempt (via the waker just registered)
+                    cx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+
+    /// Waits for at least one value, then moves up to `limit` values into `buffer`.
+    ///
+    /// Resolves to the number of values appended to `buffer`, which is `0` only if
+    /// `limit` is `0`. Once the sender drops and the channel is drained, it resolves to
+    /// [`TryRecvError::Disconnected`].
+    ///
+    /// Under load, the receiving task is woken once per batch instead of once per value:
+    /// the items are moved with a single `Acquire` load of the tail and a single `Release`
+    /// store of the head, like [`drain()`](Self::drain), and the sender is woken once.
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe. Values are only moved into `buffer` when it completes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 8>();
+    /// for i in 0..5 {
+    ///     tx.try_send(i).unwrap();
+    /// }
+    ///
+    /// let mut buffer = Vec::new();
+    /// assert_eq!(rx.recv_many(&mut buffer, 3).await.unwrap(), 3);
+    /// assert_eq!(rx.recv_many(&mut buffer, 3).await.unwrap(), 2);
+    /// assert_eq!(buffer, [0, 1, 2, 3, 4]);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn recv_many<'a>(
+        &'a mut self,
+        buffer: &'a mut Vec<T>,
+        limit: usize,
+    ) -> RecvManyFuture<'a, T, N> {
+        RecvManyFuture::new(self, buffer, limit)
+    }
+
+    /// Polls for a batch of values, registering the receiver waker while the channel is empty.
+    ///
+    /// This is what [`recv_many()`](Self::recv_many) does on each poll:
+    /// - `Poll::Ready(Ok(count))`: `count` values have been appended to `buffer`, and the
+    ///   sender woken up
+    /// - `Poll::Pending`: `cx` is woken once a value is sent or the sender drops
+    /// - `Poll::Ready(Err(TryRecvError::Disconnected))`: the sender dropped and the channel is drained
+    #[cfg(feature = "async")]
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buffer: &mut Vec<T>,
+        limit: usize,
+    ) -> Poll<Result<usize, TryRecvError>> {
+        if limit == 0 {
+            return Poll::Ready(Ok(0));
+        }
+
+        // Checked before draining: the values sent before the disconnection are then visible
+        let closed = self.is_closed();
+
+        let len = buffer.len();
+        buffer.extend(self.drain(limit));
+        let count = buffer.len() - len;
+
+        if count > 0 {
+            // The sender has been notified by the drain
+            self.reset_linger();
+            return Poll::Ready(Ok(count));
+        }
+        if closed {
+            return Poll::Ready(Err(TryRecvError::Disconnected));
+        }
+
+        self.poll_linger(cx);
+        // we store the waker for future polls
+        self.inner.register_receiver_waker(cx.waker());
+
+        // We give a second shot to see if we should be woken up immediately
+        if !self.is_empty() || self.is_closed() {
+            // Data is now available (or the sender dropped), self-wake
+            cx.waker().wake_by_ref();
+        }
+        Poll::Pending
+    }
+
+    /// Sets how many values the futures and the [`Stream`](futures::Stream) hand out in a row
+    /// before yielding to the executor, or lifts the limit with `None`.
+    ///
+    /// While the sender keeps the channel full, receiving is always ready: a task looping on
+    /// [`recv()`](Self::recv) would then starve the other tasks of its executor. Once the
+    /// budget is spent, the future returns `Pending` once, after waking its task. Defaults
+    /// to `128`, like the task budget of tokio.
+    ///
+    /// The budget belongs to the receiver rather than to the task. With the `tokio-coop`
+    /// feature, receiving also consumes the budget of the tokio task, shared with the other
+    /// tokio resources it uses.
+    /// [`poll_recv()`](Self::poll_recv) and [`poll_recv_many()`](Self::poll_recv_many) are
+    /// not budgeted: hand-written futures decide when to yield.
+    #[cfg(feature = "async")]
+    pub fn set_coop_budget(&mut self, budget: Option<u32>) {
+        self.budget.set(budget);
+    }
+
+    /// Runs `poll` within the cooperative budget, see [`set_coop_budget()`](Self::set_coop_budget).
+    #[cfg(feature = "async")]
+    #[inline]
+    fn poll_coop<R>(
+        &mut self,
+        cx: &mut Context<'_>,
+        poll: impl FnOnce(&mut Self, &mut Context<'_>) -> Poll<R>,
+    ) -> Poll<R> {
+        let proceed = std::task::ready!(self.budget.poll_proceed(cx));
+        let result = poll(self, cx);
+        self.budget.record(proceed, result)
+    }
+
+    /// Arms the linger on the first wait, before registering the waker: the sleep wakes the
+    /// task once it passed, and the sender then wakes it on any value.
+    #[cfg(feature = "async")]
+    fn poll_linger(&mut self, cx: &mut Context<'_>) {
+        let Some(linger) = self.linger else {
+            return;
+        };
+        let sleep = match &mut self.linger_sleep {
+            Some(sleep) => sleep,
+            sleep @ None => {
+                // Too far in the future to be represented: never lingers
+                let Some(deadline) = Instant::now().checked_add(linger) else {
+                    return;
+                };
+                sleep.insert(ThreadTimer.sleep_until(deadline))
+            }
+        };
+        if Pin::new(sleep).poll(cx).is_ready() {
+            self.inner.set_receiver_lingered(true);
+        }
+    }
+
+    /// Ends the wait once a value is received: the next wait lingers again.
+    #[cfg(feature = "async")]
+    #[inline]
+    fn reset_linger(&mut self) {
+        if self.linger_sleep.take().is_some() {
+            self.inner.set_receiver_lingered(false);
+        }
+    }
+
+    /// Coalesces the wakes of the receiving task: once it waits for a value, it is only woken
+    /// when `high` values are buffered, instead of on every value sent.
+    ///
+    /// `high` is clamped to `1..=capacity`, and defaults to `1`. Under a steady load, each wake
+    /// then yields a batch, best received with [`recv_many()`](Self::recv_many).
+    ///
+    /// With a `linger`, a task waiting below the watermark wakes itself once it waited that long,
+    /// through the [`ThreadTimer`](crate::spsc::timer::ThreadTimer), even if the sender went
+    /// idle: it is then woken on any value until it receives one. Threads parked in
+    /// [`recv_blocking`](Self::recv_blocking) are unparked on every value regardless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 64>();
+    /// rx.set_high_watermark(16, Some(Duration::from_millis(1)));
+    ///
+    /// let producer = async move {
+    ///     for i in 0..100 {
+    ///         tx.send(i).await.unwrap();
+    ///     }
+    ///     tx.flush().await.unwrap();
+    /// };
+    /// let consumer = async move {
+    ///     let mut received = Vec::new();
+    ///     // Woken once 16 values are buffered, rather than once per value
+    ///     while rx.recv_many(&mut received, 64).await.is_ok() {}
+    ///     received
+    /// };
+    /// let ((), received) = futures::join!(producer, consumer);
+    /// assert_eq!(received, (0..100).collect::<Vec<_>>());
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn set_high_watermark(&mut self, high: usize, linger: Option<Duration>) {
+        let high = high.clamp(1, self.capacity());
+        self.inner.set_receiver_watermark(high);
+        // With a watermark of 1, the task is woken on every value anyway
+        self.linger = linger.filter(|_| high > 1);
+        self.reset_linger();
+    }
+
+    /// Capacity of a channel sized at compile time, usable in `const` contexts.
+    ///
+    /// Channels sized at runtime ([`DYNAMIC`](crate::DYNAMIC)) don't know theirs until built, so
+    /// using it on them fails to compile: see [`capacity()`](Self::capacity) instead.
+    ///
+    /// ```
+    /// use veloce::spsc::lamport::Receiver;
+    ///
+    /// const CAPACITY: usize = Receiver::<u64, 8>::CAPACITY;
+    /// assert_eq!(CAPACITY, 8);
+    /// ```
+    ///
+    /// ```compile_fail,E0080
+    /// use veloce::{DYNAMIC, spsc::lamport::Receiver};
+    ///
+    /// let _ = Receiver::<u64, DYNAMIC>::CAPACITY;
+    /// ```
+    pub const CAPACITY: usize = {
+        assert!(
+            N != crate::ring::DYNAMIC,
+            "the capacity is only known at runtime"
+        );
         N
+    };
+
+    /// Returns the channel capacity.
+    ///
+    /// For channels sized at compile time, it is [`Self::CAPACITY`].
+    pub fn capacity(&self) -> usize {
+        self.inner.buffer.capacity()
     }
 
     /// Returns true if the sender has been dropped.
@@ -100,7 +555,50 @@ impl<T, const N: usize> Receiver<T, N> {
 
     /// Returns approximate number of items in the channel.
     pub fn len(&self) -> usize {
-        self.cursors().remaining()
+        let c = self.cursors();
+        self.inner.buffer.distance(c.head, c.tail)
+    }
+
+    /// Returns a reference to the next value, without consuming it.
+    ///
+    /// Returns `None` if the channel is empty. Receiving requires `&mut self`, so the value
+    /// can't be consumed while the reference is alive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::lamport::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<i32, 4>();
+    /// assert_eq!(rx.peek(), None);
+    ///
+    /// tx.try_send(1).unwrap();
+    /// tx.try_send(2).unwrap();
+    /// assert_eq!(rx.peek(), Some(&1));
+    /// assert_eq!(rx.iter_buffered().copied().collect::<Vec<_>>(), vec![1, 2]);
+    ///
+    /// // nothing has been consumed
+    /// assert_eq!(rx.try_recv().unwrap(), 1);
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        let cursors = self.cursors();
+        if cursors.is_empty() {
+            return None;
+        }
+
+        // Safety: head < tail, so the slot is initialized
+        Some(unsafe { self.peek_at(cursors.head) })
+    }
+
+    /// Iterates over the values published but not consumed yet, without consuming them.
+    ///
+    /// Receiving requires `&mut self`, so the values can't be consumed while the iterator
+    /// or the references it yields are alive.
+    ///
+    /// Yields only items available at construction (snapshot semantics).
+    pub fn iter_buffered(&self) -> Buffered<'_, T, N> {
+        let cursors = self.cursors();
+        Buffered { rx: self, cursors }
     }
 
     /// Drains up to `max` available items from the channel.
@@ -145,9 +643,9 @@ impl<T, const N: usize> Receiver<T, N> {
 
         // Clamp tail so we yield at most `max` items.
         // Compare counts (not raw sequence numbers) to handle wrap-around.
-        let available = cursors.remaining();
+        let available = self.inner.buffer.distance(cursors.head, cursors.tail);
         if max < available {
-            cursors.tail = original_head.wrapping_add(max);
+            cursors.tail = self.inner.buffer.advance(original_head, max);
         }
 
         Drain {
@@ -172,6 +670,54 @@ impl<T, const N: usize> Receiver<T, N> {
         Cursors { head, tail }
     }
 
+    /// Returns the `head` if a value is ready to be read.
+    fn ready(&self) -> Result<usize, TryRecvError> {
+        let mut cursors = self.cursors();
+
+        if cursors.is_empty() {
+            // Disconnection check happens only when we are sure that there are no more messages to read
+            if !self.is_closed() {
+                return Err(TryRecvError::Empty);
+            }
+
+            // The sender may have pushed right before dropping: having observed `closed`,
+            // its last `tail` store is visible now
+            cursors = self.cursors();
+            if cursors.is_empty() {
+                return Err(TryRecvError::Disconnected);
+            }
+        }
+
+        Ok(cursors.head)
+    }
+
+    /// Hands the slot at `head` back to the producer, once its value has been moved out or dropped.
+    #[inline]
+    fn release(&self, head: usize) {
+        // release-store: make sure that acquire-loads see also the previous readings on the buffer
+        self.inner
+            .head
+            .store(self.inner.buffer.next(head), Ordering::Release);
+
+        // Wake the sender if it waits for a free slot (cheap when it doesn't)
+        self.inner.notify_sender_at(|low| self.frees_at_least(low));
+    }
+
+    /// Returns true if at least `count` slots are free.
+    fn frees_at_least(&self, count: usize) -> bool {
+        self.len() + count <= self.capacity()
+    }
+
+    /// Returns a reference to the value at `seq`, leaving it in the buffer.
+    ///
+    /// # Safety
+    ///
+    /// The slot must hold a published value, not consumed while the reference is alive.
+    unsafe fn peek_at(&self, seq: usize) -> &T {
+        let i = self.inner.buffer.index(seq);
+        unsafe { (*self.inner.buffer.get(i).get()).assume_init_ref() }
+    }
+
     /// Maps the sequence to the ring-buffer index, reading the value from the buffer.
     ///
     /// Notice: it doesn't update the head
@@ -185,14 +731,58 @@ impl<T, const N: usize> Drop for Receiver<T, N> {
     fn drop(&mut self) {
         self.inner.closed.store(true, Ordering::Release);
 
-        #[cfg(feature = "async")]
         // wake the other half to let it acknowledge disconnection
-        self.inner.wake_sender();
+        self.inner.notify_sender();
     }
 }
 
-unsafe impl<T: Send, const N: usize> Sync for Receiver<T, N> {}
-unsafe impl<T: Send, const N: usize> Send for Receiver<T, N> {}
+impl<T, const N: usize> SpscReceiver for Receiver<T, N> {
+    type Item = T;
+    type Drain<'a>
+        = Drain<'a, T, N>
+    where
+        Self: 'a;
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        Receiver::try_recv(self)
+    }
+
+    fn recv_spin(&mut self) -> Result<T, TryRecvError> {
+        Receiver::recv_spin(self)
+    }
+
+    fn drain(&mut self, max: usize) -> Drain<'_, T, N> {
+        Receiver::drain(self, max)
+    }
+
+    fn capacity(&self) -> usize {
+        Receiver::capacity(self)
+    }
+
+    fn is_closed(&self) -> bool {
+        Receiver::is_closed(self)
+    }
+
+    fn len(&self) -> usize {
+        Receiver::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        Receiver::is_empty(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, const N: usize> SpscAsyncReceiver for Receiver<T, N> {
+    type RecvFuture<'a>
+        = RecvFuture<'a, T, N>
+    where
+        Self: 'a;
+
+    fn recv(&mut self) -> RecvFuture<'_, T, N> {
+        Receiver::recv(self)
+    }
+}
 
 /// Draining iterator created by [`Receiver::drain()`].
 ///
@@ -207,7 +797,7 @@ pub struct Drain<'a, T, const N: usize> {
 }
 
 impl<T, const N: usize> Drain<'_, T, N> {
-    /// Writes the current head back to the channel (Release).
+    /// Writes the current head back to the channel (Release), and notifies the sender.
     /// Skipped if nothing was consumed.
     #[inline]
     fn commit_head(&self) {
@@ -216,6 +806,9 @@ impl<T, const N: usize> Drain<'_, T, N> {
                 .inner
                 .head
                 .store(self.cursors.head, Ordering::Release);
+            self.rx
+                .inner
+                .notify_sender_at(|low| self.rx.frees_at_least(low));
         }
     }
 
@@ -228,7 +821,10 @@ impl<T, const N: usize> Drain<'_, T, N> {
     /// Returns how many items are left in this drain.
     #[inline]
     pub fn remaining(&self) -> usize {
-        self.cursors.remaining()
+        self.rx
+            .inner
+            .buffer
+            .distance(self.cursors.head, self.cursors.tail)
     }
 }
 
@@ -248,19 +844,108 @@ impl<T, const N: usize> Iterator for Drain<'_, T, N> {
         let out = unsafe { self.rx.read(head) };
 
         // Update ephemeral head (real head is updated on `drop`)
-        self.cursors.head += 1;
+        self.cursors.head = self.rx.inner.buffer.next(head);
         Some(out)
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let r = self.cursors.remaining();
+        let r = self.remaining();
         (r, Some(r))
     }
 }
 
 impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
 
+/// Iterator over the buffered values, created by [`Receiver::iter_buffered()`].
+///
+/// Yields references to the values in `[head, tail)`, without consuming them.
+pub struct Buffered<'a, T, const N: usize> {
+    rx: &'a Receiver<T, N>,
+    /// `head` advances during iteration; `tail` is fixed at construction.
+    cursors: Cursors,
+}
+
+impl<'a, T, const N: usize> Iterator for Buffered<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cursors.is_empty() {
+            return None;
+        }
+
+        let head = self.cursors.head;
+        self.cursors.head = self.rx.inner.buffer.next(head);
+
+        // Safety: head < tail, so the slot is initialized. The receiver is borrowed,
+        // so it can't be consumed for `'a`
+        Some(unsafe { self.rx.peek_at(head) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let r = self
+            .rx
+            .inner
+            .buffer
+            .distance(self.cursors.head, self.cursors.tail);
+        (r, Some(r))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Buffered<'_, T, N> {}
+
+/// Borrow of the value at the head of the channel, created by [`Receiver::recv_ref()`].
+///
+/// On drop, the value is dropped in place and the slot is handed back to the producer.
+pub struct ReadGuard<'a, T, const N: usize> {
+    rx: &'a mut Receiver<T, N>,
+    /// Sequence of the borrowed slot.
+    head: usize,
+}
+
+impl<T, const N: usize> ReadGuard<'_, T, N> {
+    fn as_ptr(&self) -> *mut T {
+        let i = self.rx.inner.buffer.index(self.head);
+        self.rx.inner.buffer.get(i).get().cast()
+    }
+}
+
+impl<T, const N: usize> Deref for ReadGuard<'_, T, N> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // Safety: head < tail, so the slot is initialized, and the producer can't touch it
+        // until the head is released on drop
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+impl<T, const N: usize> DerefMut for ReadGuard<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.as_ptr() }
+    }
+}
+
+impl<T, const N: usize> Drop for ReadGuard<'_, T, N> {
+    fn drop(&mut self) {
+        /// Releases the slot even if the destructor of the value panics,
+        /// so that the channel doesn't drop it a second time
+        struct Release<'g, 'a, T, const N: usize>(&'g ReadGuard<'a, T, N>);
+        impl<T, const N: usize> Drop for Release<'_, '_, T, N> {
+            fn drop(&mut self) {
+                self.0.rx.release(self.0.head);
+            }
+        }
+
+        let release = Release(self);
+        unsafe { ptr::drop_in_place(release.0.as_ptr()) };
+    }
+}
+
 impl<T, const N: usize> Drop for Drain<'_, T, N> {
     fn drop(&mut self) {
         self.commit_head();
@@ -272,14 +957,21 @@ mod r#async {
 
     use std::{
         pin::Pin,
-        task::{Context, Poll, Waker},
+        task::{Context, Poll, ready},
     };
 
+    use futures::Stream;
+
     use super::*;
+    use crate::spsc::{
+        timer::{ThreadTimer, Timer},
+        wait::Spin,
+    };
 
     #[must_use = "futures do nothing unless polled"]
     pub struct RecvFuture<'a, T, const N: usize> {
-        receiver: &'a Receiver<T, N>,
+        receiver: &'a mut Receiver<T, N>,
+        spin: Spin,
     }
 
     /// Safe: the struct is not self-referential:
@@ -287,45 +979,183 @@ mod r#async {
     impl<T, const N: usize> Unpin for RecvFuture<'_, T, N> {}
 
     impl<'a, T, const N: usize> RecvFuture<'a, T, N> {
-        pub fn new(receiver: &'a Receiver<T, N>) -> Self {
-            Self { receiver }
-        }
-
-        fn register_waker(&self, waker: &Waker) {
-            self.receiver.inner.register_receiver_waker(waker);
+        pub fn new(receiver: &'a mut Receiver<T, N>) -> Self {
+            Self {
+                receiver,
+                spin: Spin::Never,
+            }
         }
 
-        fn wake_sender(&self) {
-            self.receiver.inner.wake_sender();
+        /// Spins while the channel is empty, before registering the waker and yielding.
+        ///
+        /// For latency-critical consumers on a dedicated runtime thread: a value sent while
+        /// spinning is received without going through the waker and the executor. The thread
+        /// is busy in the meantime, so other tasks can't run on it.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use veloce::spsc::{lamport::channel, wait::Spin};
+        ///
+        /// # futures::executor::block_on(async {
+        /// let (mut tx, mut rx) = channel::<u32, 4>();
+        ///
+        /// let producer = std::thread::spawn(move || tx.send_spin(1).unwrap());
+        /// let value = rx.recv().with_spin(Spin::Iterations(10_000)).await;
+        /// assert_eq!(value.unwrap(), 1);
+        /// producer.join().unwrap();
+        /// # });
+        /// ```
+        pub fn with_spin(mut self, spin: Spin) -> Self {
+            self.spin = spin;
+            self
         }
     }
 
     impl<'a, T, const N: usize> Future for RecvFuture<'a, T, N> {
         type Output = Result<T, TryRecvError>;
-        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-            match self.receiver.try_recv() {
-                Ok(v) => {
-                    // Consume a value from the buffer, waking sender who might be waiting for some free space in the buffer
-                    self.wake_sender();
-                    Poll::Ready(Ok(v))
-                }
-                Err(TryRecvError::Empty) => {
-                    // we store the waker for future polls
-                    self.register_waker(cx.waker());
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let spin = self.spin;
+            self.receiver.poll_coop(cx, |receiver, cx| {
+                spin.until(|| !receiver.is_empty() || receiver.is_closed());
+                receiver.poll_recv(cx)
+            })
+        }
+    }
 
-                    // We give a second shot to see if we should be woken up immediately
-                    let tail = self.receiver.inner.tail.load(Ordering::Acquire);
-                    let head = self.receiver.inner.head.load(Ordering::Relaxed);
+    #[must_use = "futures do nothing unless polled"]
+    pub struct RecvTimeout<'a, T, const N: usize, M: Timer = ThreadTimer> {
+        receiver: &'a mut Receiver<T, N>,
+        /// `None` if too far in the future to be represented: waits forever
+        deadline: Option<Instant>,
+        timer: M,
+        /// Started on the first poll that has to wait
+        sleep: Option<M::Sleep>,
+    }
 
-                    // Check if producer pushed some data in the meanwhile
-                    if tail != head {
-                        // New data is available, self-wake to try another recv attempt (via the waker just registered)
-                        cx.waker().wake_by_ref();
-                    }
-                    Poll::Pending
-                }
-                Err(e) => Poll::Ready(Err(e)),
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize, M: Timer> Unpin for RecvTimeout<'_, T, N, M> {}
+
+    impl<'a, T, const N: usize> RecvTimeout<'a, T, N> {
+        pub fn new(receiver: &'a mut Receiver<T, N>, timeout: Duration) -> Self {
+            Self {
+                receiver,
+                deadline: Instant::now().checked_add(timeout),
+                timer: ThreadTimer,
+                sleep: None,
+            }
+        }
+    }
+
+    impl<'a, T, const N: usize, M: Timer> RecvTimeout<'a, T, N, M> {
+        /// Tracks the deadline with `timer` rather than with the
+        /// [`ThreadTimer`](crate::spsc::timer::ThreadTimer), e.g. to use the timer of the runtime.
+        pub fn with_timer<M2: Timer>(self, timer: M2) -> RecvTimeout<'a, T, N, M2> {
+            RecvTimeout {
+                receiver: self.receiver,
+                deadline: self.deadline,
+                timer,
+                sleep: None,
+            }
+        }
+    }
+
+    impl<'a, T, const N: usize, M: Timer> Future for RecvTimeout<'a, T, N, M> {
+        type Output = Result<T, RecvTimeoutError>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            match this.receiver.poll_coop(cx, Receiver::poll_recv) {
+                Poll::Ready(Ok(v)) => return Poll::Ready(Ok(v)),
+                Poll::Ready(Err(_)) => return Poll::Ready(Err(RecvTimeoutError::Disconnected)),
+                Poll::Pending => {}
+            }
+
+            let Some(deadline) = this.deadline else {
+                return Poll::Pending;
+            };
+            let timer = &this.timer;
+            let sleep = this
+                .sleep
+                .get_or_insert_with(|| timer.sleep_until(deadline));
+            ready!(Pin::new(sleep).poll(cx));
+            Poll::Ready(Err(RecvTimeoutError::Timeout))
+        }
+    }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct RecvOwned<T, const N: usize> {
+        receiver: Option<Receiver<T, N>>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize> Unpin for RecvOwned<T, N> {}
+
+    impl<T, const N: usize> RecvOwned<T, N> {
+        pub fn new(receiver: Receiver<T, N>) -> Self {
+            Self {
+                receiver: Some(receiver),
             }
         }
     }
+
+    impl<T, const N: usize> Future for RecvOwned<T, N> {
+        type Output = (Receiver<T, N>, Result<T, TryRecvError>);
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let receiver = self.receiver.as_mut().expect("polled after completion");
+            let result = ready!(receiver.poll_coop(cx, Receiver::poll_recv));
+            // The receiver is handed back only once the future completes
+            let receiver = self.receiver.take().expect("polled after completion");
+            Poll::Ready((receiver, result))
+        }
+    }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct RecvManyFuture<'a, T, const N: usize> {
+        receiver: &'a mut Receiver<T, N>,
+        buffer: &'a mut Vec<T>,
+        limit: usize,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize> Unpin for RecvManyFuture<'_, T, N> {}
+
+    impl<'a, T, const N: usize> RecvManyFuture<'a, T, N> {
+        pub fn new(receiver: &'a mut Receiver<T, N>, buffer: &'a mut Vec<T>, limit: usize) -> Self {
+            Self {
+                receiver,
+                buffer,
+                limit,
+            }
+        }
+    }
+
+    impl<'a, T, const N: usize> Future for RecvManyFuture<'a, T, N> {
+        type Output = Result<usize, TryRecvError>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            let (buffer, limit) = (&mut *this.buffer, this.limit);
+            this.receiver.poll_coop(cx, |receiver, cx| {
+                receiver.poll_recv_many(cx, buffer, limit)
+            })
+        }
+    }
+
+    /// Yields the received values, and ends once the sender drops (or closes its sink)
+    /// and the channel has been drained.
+    impl<T, const N: usize> Stream for Receiver<T, N> {
+        type Item = T;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+            self.get_mut()
+                .poll_coop(cx, Receiver::poll_recv)
+                .map(Result::ok)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.len(), None)
+        }
+    }
 }
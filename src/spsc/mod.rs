@@ -9,9 +9,23 @@
 //!
 //! - [`lamport`] — Classic approach with shared atomic head/tail indices. It is extremely cheap to create
 //! - [`vyukov`] — Per-slot sequence stamps for reduced cache contention
+//!
+//! Their halves implement [`SpscSender`] and [`SpscReceiver`], for code generic over the algorithm,
+//! and `SpscAsyncSender` and `SpscAsyncReceiver` with the `async` feature.
+//!
+//! [`rendezvous`] provides a zero-capacity channel, where every send waits for the value to be taken.
 
 pub mod lamport;
+pub mod rendezvous;
+#[cfg(feature = "async")]
+pub mod timer;
+mod traits;
 pub mod vyukov;
+pub mod wait;
+
+#[cfg(feature = "async")]
+pub use traits::{SpscAsyncReceiver, SpscAsyncSender};
+pub use traits::{SpscReceiver, SpscSender};
 
 #[derive(Debug)]
 pub enum TrySendErr<T> {
@@ -19,6 +33,20 @@ pub enum TrySendErr<T> {
     Disconnected(T),
 }
 
+impl<T> TrySendErr<T> {
+    /// Returns the value that couldn't be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(v) | Self::Disconnected(v) => v,
+        }
+    }
+}
+
+/// Thrown by the `Sink` implementation of the senders, and by their `flush` operations:
+/// the receiver has been dropped, or the sink has been closed
+#[derive(Debug)]
+pub struct SendError;
+
 /// Thrown on disconnected channel
 #[derive(Debug)]
 pub enum TryRecvError {
@@ -26,42 +54,525 @@ pub enum TryRecvError {
     Disconnected,
 }
 
+/// Thrown by deadline-bounded receives
+#[derive(Debug)]
+pub enum RecvTimeoutError {
+    Timeout,
+    Disconnected,
+}
+
+/// Thrown by deadline-bounded sends, giving the value back
+#[derive(Debug)]
+pub enum SendTimeoutError<T> {
+    Timeout(T),
+    Disconnected(T),
+}
+
+impl<T> SendTimeoutError<T> {
+    /// Returns the value that couldn't be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Timeout(v) | Self::Disconnected(v) => v,
+        }
+    }
+}
+
+mod blocking {
+
+    use crate::sync::{
+        UnsafeCell,
+        atomic::{AtomicU8, Ordering, fence},
+        hint,
+        thread::{self, Thread},
+    };
+    use crossbeam_utils::CachePadded;
+    use std::time::Instant;
+
+    /// Nobody is parked: the thread slot is owned by the half that may park.
+    const IDLE: u8 = 0;
+    /// A thread is (about to be) parked: the thread slot is readable by the other half.
+    const PARKED: u8 = 1;
+    /// The other half is taking the thread handle out of the slot to unpark it.
+    const UNPARKING: u8 = 2;
+
+    /// Hand-off of the handle of a thread parked on one half of the channel.
+    ///
+    /// Exactly one thread parks (the owner of the half) and exactly one thread
+    /// unparks (the owner of the other half), so a small state machine is enough
+    /// to move the [`Thread`] handle between them without locks.
+    pub(super) struct Parker {
+        state: AtomicU8,
+        thread: UnsafeCell<Option<Thread>>,
+    }
+
+    impl Default for Parker {
+        fn default() -> Self {
+            Self {
+                state: AtomicU8::new(IDLE),
+                thread: UnsafeCell::new(None),
+            }
+        }
+    }
+
+    impl Parker {
+        /// Parks the current thread until [`unpark`](Self::unpark) is called or `deadline` is reached.
+        ///
+        /// `ready` is evaluated after the thread has been published: if the other half
+        /// made progress in the meantime, the thread doesn't park at all.
+        /// Callers must re-check their condition on return (spurious wakeups are possible).
+        pub(super) fn park(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+            // The slot is owned by us while the state is IDLE
+            self.thread
+                .with_mut(|slot| unsafe { *slot = Some(thread::current()) });
+            self.state.store(PARKED, Ordering::Release);
+
+            // Pairs with the fence in `unpark`: either we observe the progress of the other half,
+            // or the other half observes the PARKED state
+            fence(Ordering::SeqCst);
+
+            if !ready() {
+                match deadline {
+                    None => thread::park(),
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now < deadline {
+                            thread::park_timeout(deadline - now);
+                        }
+                    }
+                }
+            }
+
+            // Take back the ownership of the slot, waiting for an in-flight unpark to release it
+            if self
+                .state
+                .compare_exchange(PARKED, IDLE, Ordering::Acquire, Ordering::Acquire)
+                .is_err()
+            {
+                while self.state.load(Ordering::Acquire) != IDLE {
+                    hint::spin_loop();
+                }
+            }
+        }
+
+        /// Unparks the thread parked on this slot, if any.
+        ///
+        /// Must follow a `SeqCst` fence, pairing with the one in [`park`](Self::park):
+        /// see [`Waiters`](super::waiters::Waiters). Cheap when nobody is parked: a load,
+        /// no read-modify-write.
+        pub(super) fn unpark(&self) {
+            if self.state.load(Ordering::Relaxed) != PARKED {
+                return;
+            }
+
+            if self
+                .state
+                .compare_exchange(PARKED, UNPARKING, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                // The slot is owned by us while the state is UNPARKING
+                let thread = self.thread.with_mut(|slot| unsafe { (*slot).take() });
+                self.state.store(IDLE, Ordering::Release);
+
+                if let Some(thread) = thread {
+                    thread.unpark();
+                }
+            }
+        }
+    }
+
+    #[derive(Default)]
+    pub(super) struct Parkers {
+        pub(super) sender: CachePadded<Parker>,
+        pub(super) receiver: CachePadded<Parker>,
+    }
+}
+
 #[cfg(feature = "async")]
 mod r#async {
 
+    use crate::sync::{
+        AtomicWaker,
+        atomic::{AtomicBool, AtomicUsize, Ordering, fence},
+    };
     use crossbeam_utils::CachePadded;
-    use futures::task::AtomicWaker;
     use std::task::Waker;
 
+    /// Waker of the task waiting on one half, with a flag telling the other half
+    /// whether there is anybody to wake.
+    pub(super) struct Waiter {
+        waker: AtomicWaker,
+        registered: AtomicBool,
+        /// The task is only woken once the other half reaches this watermark (`1`: on any progress).
+        watermark: AtomicUsize,
+        /// Set by the task once it waited for its linger: it is then woken on any progress.
+        lingered: AtomicBool,
+    }
+
+    impl Default for Waiter {
+        fn default() -> Self {
+            Self {
+                waker: AtomicWaker::new(),
+                registered: AtomicBool::new(false),
+                watermark: AtomicUsize::new(1),
+                lingered: AtomicBool::new(false),
+            }
+        }
+    }
+
+    impl Waiter {
+        /// Registers `waker`. The caller must re-check the channel afterwards.
+        pub(super) fn register(&self, waker: &Waker) {
+            self.waker.register(waker);
+            self.registered.store(true, Ordering::Relaxed);
+
+            // Pairs with the fence before `wake`: either we observe the progress of the other
+            // half when re-checking, or the other half observes the flag
+            fence(Ordering::SeqCst);
+        }
+
+        /// Sets the watermark below which wakes are skipped.
+        pub(super) fn set_watermark(&self, watermark: usize) {
+            self.watermark.store(watermark, Ordering::Relaxed);
+        }
+
+        /// Sets whether the task waited for its linger. Set before [`register`](Self::register).
+        pub(super) fn set_lingered(&self, lingered: bool) {
+            self.lingered.store(lingered, Ordering::Relaxed);
+        }
+
+        /// Wakes the registered task, if any.
+        ///
+        /// Must follow a `SeqCst` fence, pairing with the one in [`register`](Self::register).
+        /// Cheap when no task is registered: a load, no read-modify-write.
+        pub(super) fn wake(&self) {
+            if !self.registered.load(Ordering::Relaxed) {
+                return;
+            }
+
+            // A task registering in the meantime sets the flag back after registering its
+            // waker, which is then either taken below or kept for the next wake
+            self.registered.store(false, Ordering::Relaxed);
+            self.waker.wake();
+        }
+
+        /// Like [`wake`](Self::wake), but skipped while `reached(watermark)` is false,
+        /// unless the task lingered.
+        ///
+        /// The task stays registered while its wakes are skipped.
+        pub(super) fn wake_at(&self, reached: impl FnOnce(usize) -> bool) {
+            if !self.registered.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let watermark = self.watermark.load(Ordering::Relaxed);
+            if watermark > 1 && !reached(watermark) && !self.lingered.load(Ordering::Relaxed) {
+                return;
+            }
+            self.wake();
+        }
+    }
+
+    #[derive(Default)]
     pub(super) struct Wakers {
-        pub(super) sender_waker: CachePadded<AtomicWaker>,
-        pub(super) receiver_waker: CachePadded<AtomicWaker>,
+        pub(super) sender: CachePadded<Waiter>,
+        pub(super) receiver: CachePadded<Waiter>,
+    }
+}
+
+mod waiters {
+    use crate::sync::{
+        atomic::{AtomicBool, Ordering, fence},
+        barrier,
+    };
+    use std::time::{Duration, Instant};
+
+    #[cfg(feature = "async")]
+    use super::r#async::Wakers;
+    use super::blocking::Parkers;
+    #[cfg(feature = "async")]
+    use std::task::Waker;
+
+    /// Threads parked and tasks registered on the two halves of a channel.
+    ///
+    /// A waiting half announces itself (parking its thread, or registering its waker), then
+    /// re-checks the channel. The other half makes progress, then notifies: with a `SeqCst` fence
+    /// on both sides, either the waiter observes the progress, or the notifier observes the waiter.
+    /// Notifying is then cheap when nobody waits: a fence and two loads, no read-modify-write,
+    /// so every successful operation notifies, whatever the mix of sync and async halves.
+    ///
+    /// Even the fence is skipped until a half first waits, so that channels that are only polled
+    /// don't pay for it. That half arms its notifications with a [heavy](barrier::heavy) barrier:
+    /// either the other half observes the armed flag from then on, or its progress so far is
+    /// visible to the re-check. Where heavy barriers are not supported, channels start armed.
+    /// If a heavy barrier ever fails, every channel of the process notifies with the fence again.
+    ///
+    /// On Linux, the first wait of each half is then a `membarrier`, which interrupts every CPU
+    /// running a thread of the process: creating many short-lived channels that each wait once
+    /// pays for it on every channel.
+    ///
+    /// A task can also ask to be woken less often, once a watermark is reached: the notifier
+    /// then evaluates the occupancy of the channel, but only when the task is registered.
+    /// Parked threads ignore the watermarks.
+    pub(crate) struct Waiters {
+        parkers: Parkers,
+
+        #[cfg(feature = "async")]
+        wakers: Wakers,
+
+        /// Set by the sender before it first waits: until then, notifying it is skipped.
+        sender_armed: AtomicBool,
+        /// Set by the receiver before it first waits.
+        receiver_armed: AtomicBool,
+    }
+
+    impl Default for Waiters {
+        fn default() -> Self {
+            let armed = !barrier::supported();
+            Self {
+                parkers: Parkers::default(),
+                #[cfg(feature = "async")]
+                wakers: Wakers::default(),
+                sender_armed: AtomicBool::new(armed),
+                receiver_armed: AtomicBool::new(armed),
+            }
+        }
+    }
+
+    /// How long a half waits at most when arming it failed, before re-checking the channel.
+    const ARM_RETRY: Duration = Duration::from_millis(1);
+
+    /// Arms the notifications of a half, before it waits for the first time.
+    ///
+    /// Only the waiting half writes its flag. Returns false if the heavy barrier failed: the
+    /// other half may then have made progress without notifying, or being visible yet, so the
+    /// caller must re-check the channel soon instead of waiting for a notification.
+    fn arm(armed: &AtomicBool) -> bool {
+        if armed.load(Ordering::Relaxed) {
+            return true;
+        }
+        armed.store(true, Ordering::Relaxed);
+        // Pairs with the light barrier in `is_armed`
+        barrier::heavy()
+    }
+
+    /// Returns true if the half may wait, and must then be notified.
+    #[inline(always)]
+    fn is_armed(armed: &AtomicBool) -> bool {
+        // Keeps the progress being notified before the load, see `arm`
+        barrier::light();
+        armed.load(Ordering::Relaxed) || barrier::failed()
+    }
+
+    /// Caps `deadline` to [`ARM_RETRY`] from now.
+    fn retry_deadline(deadline: Option<Instant>) -> Option<Instant> {
+        let retry = Instant::now() + ARM_RETRY;
+        Some(deadline.map_or(retry, |deadline| deadline.min(retry)))
+    }
+
+    impl Waiters {
+        pub(crate) fn park_sender(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+            let deadline = if arm(&self.sender_armed) {
+                deadline
+            } else {
+                retry_deadline(deadline)
+            };
+            self.parkers.sender.park(deadline, ready);
+        }
+
+        pub(crate) fn park_receiver(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+            let deadline = if arm(&self.receiver_armed) {
+                deadline
+            } else {
+                retry_deadline(deadline)
+            };
+            self.parkers.receiver.park(deadline, ready);
+        }
+
+        /// Registers the waker of the sender task. If arming failed, the task is woken right
+        /// away to re-check the channel.
+        #[cfg(feature = "async")]
+        pub(crate) fn register_sender_waker(&self, waker: &Waker) {
+            let armed = arm(&self.sender_armed);
+            self.wakers.sender.register(waker);
+            if !armed {
+                waker.wake_by_ref();
+            }
+        }
+
+        /// Registers the waker of the receiver task. If arming failed, the task is woken right
+        /// away to re-check the channel.
+        #[cfg(feature = "async")]
+        pub(crate) fn register_receiver_waker(&self, waker: &Waker) {
+            let armed = arm(&self.receiver_armed);
+            self.wakers.receiver.register(waker);
+            if !armed {
+                waker.wake_by_ref();
+            }
+        }
+
+        /// Unparks the sender thread, or wakes the sender task, if it waits.
+        pub(crate) fn notify_sender(&self) {
+            if !is_armed(&self.sender_armed) {
+                return;
+            }
+            fence(Ordering::SeqCst);
+            self.parkers.sender.unpark();
+            #[cfg(feature = "async")]
+            self.wakers.sender.wake();
+        }
+
+        /// Unparks the receiver thread, or wakes the receiver task, if it waits.
+        pub(crate) fn notify_receiver(&self) {
+            if !is_armed(&self.receiver_armed) {
+                return;
+            }
+            fence(Ordering::SeqCst);
+            self.parkers.receiver.unpark();
+            #[cfg(feature = "async")]
+            self.wakers.receiver.wake();
+        }
+
+        /// Like [`notify_sender`](Self::notify_sender), but a sender task is only woken once
+        /// `free(watermark)` says that enough slots are free (see
+        /// [`set_sender_watermark`](Self::set_sender_watermark)).
+        pub(crate) fn notify_sender_at(
+            &self,
+            #[cfg_attr(not(feature = "async"), allow(unused_variables))] free: impl FnOnce(
+                usize,
+            )
+                -> bool,
+        ) {
+            if !is_armed(&self.sender_armed) {
+                return;
+            }
+            fence(Ordering::SeqCst);
+            self.parkers.sender.unpark();
+            #[cfg(feature = "async")]
+            self.wakers.sender.wake_at(free);
+        }
+
+        /// Like [`notify_receiver`](Self::notify_receiver), but a receiver task is only woken
+        /// once `buffered(watermark)` says that enough values are buffered, or on any value once
+        /// it lingered (see [`set_receiver_watermark`](Self::set_receiver_watermark)).
+        pub(crate) fn notify_receiver_at(
+            &self,
+            #[cfg_attr(not(feature = "async"), allow(unused_variables))] buffered: impl FnOnce(
+                usize,
+            )
+                -> bool,
+        ) {
+            if !is_armed(&self.receiver_armed) {
+                return;
+            }
+            fence(Ordering::SeqCst);
+            self.parkers.receiver.unpark();
+            #[cfg(feature = "async")]
+            self.wakers.receiver.wake_at(buffered);
+        }
+
+        /// Wakes the sender task once `low` slots are free, instead of on every freed slot.
+        #[cfg(feature = "async")]
+        pub(crate) fn set_sender_watermark(&self, low: usize) {
+            self.wakers.sender.set_watermark(low);
+        }
+
+        /// Wakes the receiver task once `high` values are buffered, instead of on every value.
+        #[cfg(feature = "async")]
+        pub(crate) fn set_receiver_watermark(&self, high: usize) {
+            self.wakers.receiver.set_watermark(high);
+        }
+
+        /// Wakes the receiver task on any value again, once it waited for its linger without
+        /// reaching its watermark. Must be set before registering its waker.
+        #[cfg(feature = "async")]
+        pub(crate) fn set_receiver_lingered(&self, lingered: bool) {
+            self.wakers.receiver.set_lingered(lingered);
+        }
     }
+}
 
-    impl Default for Wakers {
+/// Cooperative scheduling of the async receivers.
+///
+/// While the sender keeps the channel full, receiving never returns `Pending`: a task looping on
+/// `recv().await` would never give the executor back. Each receiver then yields once, waking
+/// itself, after handing out a budget of values in a row.
+#[cfg(feature = "async")]
+mod coop {
+    use std::task::{Context, Poll};
+
+    /// Values handed out in a row before yielding, by default: tokio's own task budget.
+    const DEFAULT_BUDGET: u32 = 128;
+
+    pub(crate) struct Budget {
+        /// `0`: unconstrained.
+        limit: u32,
+        /// Values handed out since the receiver last yielded.
+        streak: u32,
+    }
+
+    impl Default for Budget {
         fn default() -> Self {
             Self {
-                sender_waker: CachePadded::new(AtomicWaker::new()),
-                receiver_waker: CachePadded::new(AtomicWaker::new()),
+                limit: DEFAULT_BUDGET,
+                streak: 0,
             }
         }
     }
 
-    impl Wakers {
-        pub(super) fn wake_sender(&self) {
-            self.sender_waker.wake()
+    impl Budget {
+        /// Hands out up to `budget` values in a row, or an unlimited number with `None`.
+        pub(crate) fn set(&mut self, budget: Option<u32>) {
+            self.limit = budget.map_or(0, |budget| budget.max(1));
+            self.streak = 0;
         }
 
-        pub(super) fn wake_receiver(&self) {
-            self.receiver_waker.wake()
+        /// Returns `Pending`, after waking the task, once the budget is exhausted.
+        ///
+        /// With the `tokio-coop` feature, the budget of the tokio task is checked as well.
+        #[inline]
+        pub(crate) fn poll_proceed(&mut self, cx: &mut Context<'_>) -> Poll<Proceed> {
+            if self.limit != 0 && self.streak >= self.limit {
+                self.streak = 0;
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
+            #[cfg(all(feature = "tokio-coop", not(loom)))]
+            let tokio = std::task::ready!(tokio::task::coop::poll_proceed(cx));
+
+            Poll::Ready(Proceed {
+                #[cfg(all(feature = "tokio-coop", not(loom)))]
+                tokio,
+            })
         }
 
-        pub(super) fn register_sender_waker(&self, waker: &Waker) {
-            self.sender_waker.register(waker);
+        /// Consumes the budget if `poll` is ready, or ends the streak.
+        #[inline]
+        pub(crate) fn record<R>(&mut self, proceed: Proceed, poll: Poll<R>) -> Poll<R> {
+            if poll.is_ready() {
+                self.streak = self.streak.saturating_add(1);
+                proceed.made_progress();
+            } else {
+                // Yielding anyway
+                self.streak = 0;
+            }
+            poll
         }
+    }
+
+    /// Permission to poll the channel, returned by [`Budget::poll_proceed`].
+    #[must_use]
+    pub(crate) struct Proceed {
+        /// Gives the budget of the tokio task back, unless progress is made.
+        #[cfg(all(feature = "tokio-coop", not(loom)))]
+        tokio: tokio::task::coop::RestoreOnPending,
+    }
 
-        pub(super) fn register_receiver_waker(&self, waker: &Waker) {
-            self.receiver_waker.register(waker);
+    impl Proceed {
+        #[inline]
+        fn made_progress(self) {
+            #[cfg(all(feature = "tokio-coop", not(loom)))]
+            self.tokio.made_progress();
         }
     }
 }
@@ -0,0 +1,202 @@
+use crate::sync::{
+    Arc, UnsafeCell,
+    atomic::{AtomicBool, AtomicU8, Ordering},
+    hint,
+};
+use std::{mem::MaybeUninit, time::Instant};
+
+use crossbeam_utils::CachePadded;
+
+use crate::{
+    ring::Storable,
+    spsc::rendezvous::{receiver::Receiver, sender::Sender},
+};
+
+use crate::spsc::waiters::Waiters;
+#[cfg(feature = "async")]
+use std::task::Waker;
+
+/// No value in the slot: the sender owns it.
+const EMPTY: u8 = 0;
+/// The sender offered a value: either half may claim the slot.
+const OFFERED: u8 = 1;
+/// The receiver is moving the value out of the slot.
+const TAKING: u8 = 2;
+/// The receiver took the value: the sender has to acknowledge it.
+const TAKEN: u8 = 3;
+
+pub(super) struct Channel<T> {
+    slot: UnsafeCell<MaybeUninit<T>>,
+    state: CachePadded<AtomicU8>,
+    pub(super) closed: CachePadded<AtomicBool>,
+    waiters: Waiters,
+}
+
+impl<T> Default for Channel<T> {
+    fn default() -> Self {
+        Self {
+            slot: UnsafeCell::new(MaybeUninit::uninit()),
+            state: CachePadded::new(AtomicU8::new(EMPTY)),
+            closed: CachePadded::new(AtomicBool::new(false)),
+            waiters: Waiters::default(),
+        }
+    }
+}
+
+impl<T> Channel<T> {
+    pub fn split(self) -> (Sender<T>, Receiver<T>) {
+        let inner = Arc::new(self);
+        let tx = Sender::new(inner.clone());
+        let rx = Receiver::new(inner);
+        (tx, rx)
+    }
+
+    /// Acquire: pairs with the Release store on drop, so that everything the dropped
+    /// half did before dropping is visible once `closed` is observed
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Sender moves `value` into the slot and makes it visible to the receiver, notifying it.
+    ///
+    /// # Safety
+    ///
+    /// Only the sender may call it, and not concurrently with [`Self::outcome`] or [`Self::retract`].
+    #[inline]
+    pub(super) unsafe fn offer(&self, value: T) {
+        if self.state.load(Ordering::Relaxed) != EMPTY {
+            // The future of the previous hand-off was leaked before completing
+            drop(self.retract());
+        }
+
+        unsafe { self.slot.write(value) };
+
+        // release-store: make sure that the receiver sees the value once it observes the offer
+        self.state.store(OFFERED, Ordering::Release);
+
+        // Wake the receiver if it waits for a value (cheap when it doesn't)
+        self.notify_receiver();
+    }
+
+    /// Receiver moves the offered value out of the slot, if any, notifying the sender.
+    #[inline]
+    pub(super) fn take(&self) -> Option<T> {
+        // Claim the slot first, so that the sender can't retract the value while we are reading it
+        self.state
+            .compare_exchange(OFFERED, TAKING, Ordering::Acquire, Ordering::Relaxed)
+            .ok()?;
+
+        let value = unsafe { self.slot.read() };
+
+        // release-store: the sender may reuse the slot once it observes the value was taken
+        self.state.store(TAKEN, Ordering::Release);
+
+        // Wake the sender waiting for the hand-off to complete
+        self.notify_sender();
+
+        Some(value)
+    }
+
+    /// Returns true if a value is waiting to be taken.
+    #[inline]
+    pub(super) fn is_offered(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == OFFERED
+    }
+
+    /// Returns true if the offered value has been taken.
+    #[inline]
+    pub(super) fn is_taken(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == TAKEN
+    }
+
+    /// Sender checks whether the hand-off of the offered value completed.
+    ///
+    /// - `Some(Ok(()))` once the receiver took the value
+    /// - `Some(Err(value))` if the receiver dropped before taking it: the value is given back
+    /// - `None` while the value is waiting to be taken
+    ///
+    /// When it returns `Some`, the slot is empty again.
+    #[inline]
+    pub(super) fn outcome(&self) -> Option<Result<(), T>> {
+        // acquire-load: the receiver finished reading the slot before storing TAKEN
+        if self.state.load(Ordering::Acquire) == TAKEN {
+            self.state.store(EMPTY, Ordering::Relaxed);
+            return Some(Ok(()));
+        }
+
+        if !self.is_closed() {
+            return None;
+        }
+
+        match self.retract() {
+            // The receiver took the value right before dropping
+            None => Some(Ok(())),
+            Some(value) => Some(Err(value)),
+        }
+    }
+
+    /// Sender takes the offered value back, if the receiver didn't take it yet.
+    ///
+    /// Waits for a receiver that is already reading the value. The slot is empty on return.
+    ///
+    /// Only the sender may call it.
+    pub(super) fn retract(&self) -> Option<T> {
+        loop {
+            match self
+                .state
+                .compare_exchange(OFFERED, EMPTY, Ordering::Acquire, Ordering::Acquire)
+            {
+                Ok(_) => return Some(unsafe { self.slot.read() }),
+                Err(TAKEN) | Err(EMPTY) => {
+                    self.state.store(EMPTY, Ordering::Relaxed);
+                    return None;
+                }
+                // The receiver is moving the value out
+                Err(_) => hint::spin_loop(),
+            }
+        }
+    }
+
+    pub(super) fn park_sender(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_sender(deadline, ready);
+    }
+
+    pub(super) fn park_receiver(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_receiver(deadline, ready);
+    }
+
+    /// Unparks or wakes the sender, if it waits: see [`Waiters`].
+    pub(super) fn notify_sender(&self) {
+        self.waiters.notify_sender();
+    }
+
+    /// Unparks or wakes the receiver, if it waits: see [`Waiters`].
+    pub(super) fn notify_receiver(&self) {
+        self.waiters.notify_receiver();
+    }
+
+    #[cfg(feature = "async")]
+    pub(super) fn register_sender_waker(&self, waker: &Waker) {
+        self.waiters.register_sender_waker(waker);
+    }
+
+    #[cfg(feature = "async")]
+    pub(super) fn register_receiver_waker(&self, waker: &Waker) {
+        self.waiters.register_receiver_waker(waker);
+    }
+}
+
+unsafe impl<T: Send> Sync for Channel<T> {}
+unsafe impl<T: Send> Send for Channel<T> {}
+
+// The channel is dropped when both Sender and Receiver have dropped
+impl<T> Drop for Channel<T> {
+    fn drop(&mut self) {
+        // A value is left in the slot only if a send future was leaked after offering it.
+        // Relaxed is fine here: Arc's drop synchronized with all previous Release stores
+        if self.state.load(Ordering::Relaxed) == OFFERED {
+            unsafe { self.slot.drop_in_place() };
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! Zero-capacity SPSC Channel
+//!
+//! A rendezvous channel: a send completes only once the receiver has actually taken the value,
+//! so the producer knows the consumer has taken ownership when the call returns.
+//! It is the lock-free SPSC counterpart of `std::sync::mpsc::sync_channel(0)`.
+//!
+//! ## How It Works
+//!
+//! The value travels through a single slot, whose state is handed back and forth:
+//!
+//!```text
+//!            offer             take
+//!  ┌───────┐ ───▶ ┌─────────┐ ───▶ ┌────────┐ ───▶ ┌───────┐
+//!  │ EMPTY │      │ OFFERED │      │ TAKING │      │ TAKEN │
+//!  └───────┘      └─────────┘      └────────┘      └───────┘
+//!      ▲  (sender)     │  retract   (receiver)          │
+//!      └───────────────┴────────────────────────────────┘
+//!                        (sender) acknowledge
+//!```
+//!
+//! - **Sender** writes the value into the slot and publishes `OFFERED`, then waits for `TAKEN`
+//! - **Receiver** claims an `OFFERED` slot, moves the value out and publishes `TAKEN`
+//! - If the receiver drops before taking the value, the sender **retracts** it and gets it back
+//!   through [`TrySendErr::Disconnected`](crate::spsc::TrySendErr::Disconnected)
+//!
+//! ## Synchronization
+//!
+//! No locks are used: publishing `OFFERED` and `TAKEN` is a `Release` store, observed through an
+//! `Acquire` load (or compare-exchange) by the other half, which hands the slot over with the
+//! value it holds.
+//!
+//! ## Blocking Support
+//!
+//! [`send_blocking()`](Sender::send_blocking) and [`recv_blocking()`](Receiver::recv_blocking)
+//! park the OS thread instead of spinning: the sender unparks the receiver when it offers a value,
+//! and the receiver unparks the sender once it has taken it.
+//!
+//! ## Async Support
+//!
+//! With the `async` feature, [`send()`](Sender::send) and [`recv()`](Receiver::recv)
+//! return futures that poll the underlying lock-free operations. The futures
+//! themselves make no OS calls—whether the OS is involved depends on your runtime.
+//!
+//! ## Thread Safety
+//!
+//! Each half can be moved to another thread, but is not `Sync`: a half is used by one thread at
+//! a time, which is what makes the single-producer single-consumer protocol sound. Operations
+//! that move values in or out of the channel take `&mut self`, and sharing a half by reference
+//! across threads doesn't compile, even to call its `&self` methods:
+//!
+//!```compile_fail,E0277
+//! use veloce::spsc::rendezvous::channel;
+//!
+//! let (_tx, rx) = channel::<i32>();
+//! let rx = &rx;
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| rx.is_closed());
+//!     s.spawn(|| rx.is_closed());
+//! });
+//! ```
+//!
+//!```compile_fail,E0277
+//! use veloce::spsc::rendezvous::channel;
+//!
+//! let (tx, _rx) = channel::<i32>();
+//! let tx = &tx;
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| tx.is_closed());
+//!     s.spawn(|| tx.is_closed());
+//! });
+//! ```
+//!
+//! ## Example
+//!
+//!```rust
+//! use veloce::spsc::rendezvous::channel;
+//!
+//! let (mut tx, mut rx) = channel::<i32>();
+//!
+//! let consumer = std::thread::spawn(move || rx.recv_blocking().unwrap());
+//!
+//! // Returns once the consumer has taken the value
+//! tx.send_blocking(1).unwrap();
+//! assert_eq!(consumer.join().unwrap(), 1);
+//! ```
+mod channel;
+mod receiver;
+mod sender;
+
+use channel::Channel;
+pub use receiver::Receiver;
+#[cfg(feature = "async")]
+pub use receiver::RecvFuture;
+#[cfg(feature = "async")]
+pub use sender::SendFuture;
+pub use sender::Sender;
+
+/// Creates a rendezvous channel: every send waits for the receiver to take the value.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    Channel::default().split()
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::{sync::Arc, thread::sleep, time::Duration};
+
+    use crate::spsc::{TryRecvError, TrySendErr};
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct DropCounter(Arc<AtomicUsize>);
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// The send returns only after the receiver has taken the value
+    #[test]
+    fn test_rendezvous() {
+        let (mut tx, mut rx) = channel::<i32>();
+        let sent = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let sent = sent.clone();
+            std::thread::spawn(move || {
+                tx.send_spin(1).unwrap();
+                sent.store(true, Ordering::SeqCst);
+            })
+        };
+
+        sleep(Duration::from_millis(20));
+        assert!(!sent.load(Ordering::SeqCst));
+
+        assert_eq!(rx.recv_spin().unwrap(), 1);
+        handle.join().unwrap();
+        assert!(sent.load(Ordering::SeqCst));
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Disconnected)));
+    }
+
+    /// Nothing can be received until the sender offers a value
+    #[test]
+    fn test_empty() {
+        let (_tx, mut rx) = channel::<i32>();
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+    }
+
+    /// Values are handed over in order, with both halves parking
+    #[test]
+    fn test_blocking() {
+        let (mut tx, mut rx) = channel::<usize>();
+
+        let handle = std::thread::spawn(move || {
+            for i in 0..1000 {
+                tx.send_blocking(i).unwrap();
+            }
+        });
+
+        for i in 0..1000 {
+            assert_eq!(rx.recv_blocking().unwrap(), i);
+        }
+
+        handle.join().unwrap();
+        assert!(matches!(
+            rx.recv_blocking(),
+            Err(TryRecvError::Disconnected)
+        ));
+    }
+
+    /// A waiting sender gets its value back when the receiver drops without taking it
+    #[test]
+    fn test_send_disconnected() {
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, rx) = channel::<DropCounter>();
+
+        let handle = std::thread::spawn(move || {
+            sleep(Duration::from_millis(10));
+            drop(rx);
+        });
+
+        let err = tx
+            .send_blocking(DropCounter(inner.clone()))
+            .expect_err("receiver dropped");
+        assert!(matches!(err, TrySendErr::Disconnected(..)));
+        assert_eq!(inner.load(Ordering::SeqCst), 0);
+
+        drop(err);
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+        handle.join().unwrap();
+
+        assert!(matches!(
+            tx.send_spin(DropCounter(inner.clone())),
+            Err(TrySendErr::Disconnected(..))
+        ));
+    }
+
+    /// Test the async strategy
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_channel() {
+        let (mut tx, mut rx) = channel::<i32>();
+
+        let handle = tokio::spawn(async move {
+            for i in 0..10 {
+                tx.send(i).await.unwrap();
+            }
+        });
+
+        for i in 0..10 {
+            assert_eq!(rx.recv().await.unwrap(), i);
+        }
+
+        handle.await.unwrap();
+        assert!(matches!(rx.recv().await, Err(TryRecvError::Disconnected)));
+    }
+
+    /// Dropping a pending send future retracts the value, and the channel remains usable
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_cancel() {
+        use std::{pin::pin, task::Poll};
+
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, mut rx) = channel::<DropCounter>();
+
+        {
+            let mut fut = pin!(tx.send(DropCounter(inner.clone())));
+            let poll = futures::poll!(fut.as_mut());
+            assert!(matches!(poll, Poll::Pending));
+        }
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+
+        let (sent, received) = tokio::join!(tx.send(DropCounter(inner.clone())), rx.recv());
+        sent.unwrap();
+        drop(received.unwrap());
+        assert_eq!(inner.load(Ordering::SeqCst), 2);
+    }
+
+    /// A thread spinning on `send_spin` wakes the task awaiting `recv()`
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_sync_sender_async_receiver() {
+        let (mut tx, mut rx) = channel::<i32>();
+
+        let producer = std::thread::spawn(move || {
+            for i in 0..10 {
+                tx.send_spin(i).unwrap();
+            }
+        });
+
+        for i in 0..10 {
+            assert_eq!(rx.recv().await.unwrap(), i);
+        }
+        assert!(matches!(rx.recv().await, Err(TryRecvError::Disconnected)));
+        producer.join().unwrap();
+    }
+
+    /// A thread polling `try_recv` wakes the task awaiting `send()`
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_sender_sync_receiver() {
+        let (mut tx, mut rx) = channel::<i32>();
+
+        let consumer = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            loop {
+                match rx.try_recv() {
+                    Ok(v) => received.push(v),
+                    Err(TryRecvError::Empty) => sleep(Duration::from_micros(100)),
+                    Err(TryRecvError::Disconnected) => return received,
+                }
+            }
+        });
+
+        for i in 0..10 {
+            tx.send(i).await.unwrap();
+        }
+        drop(tx);
+        assert_eq!(consumer.join().unwrap(), (0..10).collect::<Vec<_>>());
+    }
+}
+
+/// Model-checked tests: run with `RUSTFLAGS="--cfg loom" cargo test --release --lib loom`
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::thread;
+
+    use crate::{
+        spsc::{TryRecvError, TrySendErr},
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    use super::*;
+
+    struct DropCounter(Arc<AtomicUsize>);
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Explores the interleavings with up to 3 preemptions, unless `LOOM_MAX_PREEMPTIONS` is set:
+    /// an unbounded exploration of the async tests doesn't complete in a reasonable time
+    fn model(f: impl Fn() + Send + Sync + 'static) {
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound.get_or_insert(3);
+        builder.check(f);
+    }
+
+    /// Values are handed over in order
+    #[test]
+    fn loom_rendezvous() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..2 {
+                    tx.send_spin(i).unwrap();
+                }
+            });
+
+            for i in 0..2 {
+                assert_eq!(rx.recv_spin().unwrap(), i);
+            }
+            assert!(matches!(rx.recv_spin(), Err(TryRecvError::Disconnected)));
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// A value is either taken by the receiver or given back to the sender, never both
+    #[test]
+    fn loom_retract() {
+        model(|| {
+            let drops = Arc::new(AtomicUsize::new(0));
+            let (mut tx, mut rx) = channel::<DropCounter>();
+
+            let consumer = thread::spawn(move || {
+                let _ = rx.try_recv();
+            });
+
+            match tx.send_spin(DropCounter(drops.clone())) {
+                Ok(()) => {}
+                Err(TrySendErr::Disconnected(v)) => drop(v),
+                Err(TrySendErr::Full(_)) => unreachable!(),
+            }
+
+            consumer.join().unwrap();
+            assert_eq!(drops.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    /// Pending futures are always woken: a missed wakeup is reported as a deadlock
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_async() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize>();
+
+            let producer = thread::spawn(move || {
+                block_on(tx.send(1)).unwrap();
+            });
+
+            assert_eq!(block_on(rx.recv()).unwrap(), 1);
+            assert!(matches!(
+                block_on(rx.recv()),
+                Err(TryRecvError::Disconnected)
+            ));
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// A sync sender wakes an async receiver
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_sync_sender_async_receiver() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize>();
+
+            let producer = thread::spawn(move || {
+                tx.send_spin(1).unwrap();
+            });
+
+            assert_eq!(block_on(rx.recv()).unwrap(), 1);
+            producer.join().unwrap();
+        });
+    }
+
+    /// A sync receiver wakes an async sender
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_async_sender_sync_receiver() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize>();
+
+            let consumer = thread::spawn(move || {
+                assert_eq!(rx.recv_spin().unwrap(), 1);
+            });
+
+            block_on(tx.send(1)).unwrap();
+            consumer.join().unwrap();
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use crate::sync::{Arc, atomic::Ordering, hint};
+use std::{cell::Cell, marker::PhantomData};
+
+use crate::spsc::TryRecvError;
+
+use super::channel::Channel;
+
+#[cfg(feature = "async")]
+pub use r#async::RecvFuture;
+pub struct Receiver<T> {
+    pub(super) inner: Arc<Channel<T>>,
+    _not_clone: PhantomData<Cell<()>>, //marker type to avoid cloning implementations
+}
+
+impl<T> Receiver<T> {
+    pub(super) fn new(inner: Arc<Channel<T>>) -> Self {
+        Self {
+            inner,
+            _not_clone: PhantomData,
+        }
+    }
+
+    /// Consumer takes the value offered by the sender, if any.
+    ///
+    /// Taking the value completes the sender's hand-off.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        if let Some(value) = self.inner.take() {
+            return Ok(value);
+        }
+
+        // Disconnection check happens only when we are sure that there is no value to take
+        if !self.is_closed() {
+            return Err(TryRecvError::Empty);
+        }
+
+        // A value may have been offered right before the sender dropped:
+        // having observed `closed`, the offer is visible now
+        self.inner.take().ok_or(TryRecvError::Disconnected)
+    }
+
+    /// Receiver takes the next value using a busy-spin strategy.
+    ///
+    /// If no value is offered, it hints to the CPU that it is in a spin-wait (`hint::spin_loop`).
+    pub fn recv_spin(&mut self) -> Result<T, TryRecvError> {
+        loop {
+            match self.try_recv() {
+                Ok(v) => return Ok(v),
+                Err(TryRecvError::Empty) => {
+                    hint::spin_loop();
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receiver takes the next value, parking the thread until one is offered.
+    ///
+    /// The thread is unparked by the sender as soon as it offers a value, or when the sender drops.
+    /// On success, the waiting sender is unparked.
+    pub fn recv_blocking(&mut self) -> Result<T, TryRecvError> {
+        loop {
+            match self.try_recv() {
+                Ok(v) => return Ok(v),
+                Err(TryRecvError::Empty) => {
+                    self.inner
+                        .park_receiver(None, || self.is_closed() || self.inner.is_offered());
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Receiver takes the next value using a async strategy.
+    ///
+    /// - On success: wakes the sender, whose [`SendFuture`](crate::spsc::rendezvous::SendFuture)
+    ///   completes now that the value has been taken.
+    /// - When no value is offered: registers a waker and returns `Pending`. A double-check
+    ///   is performed after registration to avoid missed wakeups if the sender
+    ///   offered a value in the meantime.
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe. Dropping it before completion does not lose data.
+    #[cfg(feature = "async")]
+    pub fn recv(&mut self) -> RecvFuture<'_, T> {
+        RecvFuture::new(self)
+    }
+
+    /// Returns true if the sender has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.inner.closed.store(true, Ordering::Release);
+
+        // wake the other half to let it take its value back
+        self.inner.notify_sender();
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::*;
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct RecvFuture<'a, T> {
+        receiver: &'a mut Receiver<T>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T> Unpin for RecvFuture<'_, T> {}
+
+    impl<'a, T> RecvFuture<'a, T> {
+        pub fn new(receiver: &'a mut Receiver<T>) -> Self {
+            Self { receiver }
+        }
+
+        fn register_waker(&self, waker: &Waker) {
+            self.receiver.inner.register_receiver_waker(waker);
+        }
+    }
+
+    impl<'a, T> Future for RecvFuture<'a, T> {
+        type Output = Result<T, TryRecvError>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            match self.receiver.try_recv() {
+                // Took the value: the sender, waiting for the hand-off to complete, has been notified
+                Ok(v) => Poll::Ready(Ok(v)),
+                Err(TryRecvError::Empty) => {
+                    // we store the waker for future polls
+                    self.register_waker(cx.waker());
+
+                    // We give a second shot to see if we should be woken up immediately
+                    if self.receiver.inner.is_offered() || self.receiver.is_closed() {
+                        // A value has been offered (or the sender dropped), self-wake to take it (via the waker just registered)
+                        cx.waker().wake_by_ref();
+                    }
+                    Poll::Pending
+                }
+                Err(e) => Poll::Ready(Err(e)),
+            }
+        }
+    }
+}
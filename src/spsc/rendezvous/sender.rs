@@ -0,0 +1,176 @@
+use crate::spsc::TrySendErr;
+
+use crate::sync::{Arc, atomic::Ordering, hint};
+
+use super::channel::Channel;
+use std::{cell::Cell, marker::PhantomData};
+
+#[cfg(feature = "async")]
+pub use r#async::SendFuture;
+pub struct Sender<T> {
+    pub(super) inner: Arc<Channel<T>>,
+    _not_clone: PhantomData<Cell<()>>, //marker type to avoid cloning implementations
+}
+
+impl<T> Sender<T> {
+    pub(super) fn new(inner: Arc<Channel<T>>) -> Self {
+        Self {
+            inner,
+            _not_clone: PhantomData,
+        }
+    }
+
+    /// Producer hands `value` over to the receiver using a busy-spin strategy.
+    ///
+    /// Returns once the receiver has taken the value. If the receiver drops before taking it,
+    /// the value is given back through [`TrySendErr::Disconnected`].
+    ///
+    /// While waiting, it hints to the CPU that it is in a spin-wait (`hint::spin_loop`).
+    pub fn send_spin(&mut self, value: T) -> Result<(), TrySendErr<T>> {
+        if self.is_closed() {
+            return Err(TrySendErr::Disconnected(value));
+        }
+
+        // Safety: single producer
+        unsafe { self.inner.offer(value) };
+
+        loop {
+            match self.inner.outcome() {
+                Some(Ok(())) => return Ok(()),
+                Some(Err(v)) => return Err(TrySendErr::Disconnected(v)),
+                None => hint::spin_loop(),
+            }
+        }
+    }
+
+    /// Producer hands `value` over to the receiver, parking the thread until it is taken.
+    ///
+    /// A parked receiver is unparked by the offer, and unparks this thread once it has taken the
+    /// value, or when it drops. In the latter case, the value is given back through [`TrySendErr::Disconnected`].
+    pub fn send_blocking(&mut self, value: T) -> Result<(), TrySendErr<T>> {
+        if self.is_closed() {
+            return Err(TrySendErr::Disconnected(value));
+        }
+
+        // Safety: single producer
+        unsafe { self.inner.offer(value) };
+
+        loop {
+            match self.inner.outcome() {
+                Some(Ok(())) => return Ok(()),
+                Some(Err(v)) => return Err(TrySendErr::Disconnected(v)),
+                None => self
+                    .inner
+                    .park_sender(None, || self.is_closed() || self.inner.is_taken()),
+            }
+        }
+    }
+
+    /// Producer hands `value` over to the receiver using a async strategy.
+    ///
+    /// - The value is offered on the first poll, and the receiver's waker is notified
+    ///   so a blocked [`RecvFuture`](crate::spsc::rendezvous::RecvFuture) can take it.
+    /// - The future completes once the receiver has taken the value; it is woken by the
+    ///   [`RecvFuture`](crate::spsc::rendezvous::RecvFuture) that took it.
+    ///
+    /// # Cancel Safety
+    ///
+    /// **Not cancel-safe.** Dropping this future before completion retracts the value and drops
+    /// it, unless the receiver has already taken it.
+    #[cfg(feature = "async")]
+    pub fn send(&mut self, value: T) -> SendFuture<'_, T> {
+        SendFuture::new(self, value)
+    }
+
+    /// Returns true if the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.inner.closed.store(true, Ordering::Release);
+
+        // wake the other half to let it acknowledge disconnection
+        self.inner.notify_receiver();
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use std::{
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use super::*;
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct SendFuture<'a, T> {
+        sender: &'a mut Sender<T>,
+        value: Option<T>,
+        /// The value has been moved into the slot and the hand-off is pending
+        offered: bool,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T> Unpin for SendFuture<'_, T> {}
+
+    impl<'a, T> SendFuture<'a, T> {
+        pub fn new(sender: &'a mut Sender<T>, value: T) -> Self {
+            Self {
+                sender,
+                value: Some(value),
+                offered: false,
+            }
+        }
+
+        fn register_waker(&self, waker: &Waker) {
+            self.sender.inner.register_sender_waker(waker);
+        }
+    }
+
+    impl<'a, T> Future for SendFuture<'a, T> {
+        type Output = Result<(), TrySendErr<T>>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if !self.offered {
+                let value = self.value.take().expect("polled after completion");
+                if self.sender.is_closed() {
+                    // No need to notify as the other half is dropped
+                    return Poll::Ready(Err(TrySendErr::Disconnected(value)));
+                }
+
+                // Safety: single producer. The receiver is notified of the new value
+                unsafe { self.sender.inner.offer(value) };
+                self.offered = true;
+            }
+
+            if let Some(outcome) = self.sender.inner.outcome() {
+                self.offered = false;
+                return Poll::Ready(outcome.map_err(TrySendErr::Disconnected));
+            }
+
+            // we store the waker for future polls
+            self.register_waker(cx.waker());
+
+            // We give a second shot to see if we should be woken up immediately
+            if self.sender.inner.is_taken() || self.sender.is_closed() {
+                // Hand-off completed in the meanwhile, self-wake to acknowledge it (via the waker just registered)
+                cx.waker().wake_by_ref();
+            }
+
+            Poll::Pending
+        }
+    }
+
+    impl<T> Drop for SendFuture<'_, T> {
+        fn drop(&mut self) {
+            if self.offered {
+                // Cancelled while the value is in the slot: take it back, unless already taken
+                drop(self.sender.inner.retract());
+            }
+        }
+    }
+}
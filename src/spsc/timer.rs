@@ -0,0 +1,243 @@
+//! Timers behind the async timeouts of the channels.
+//!
+//! [`recv_timeout_async()`](crate::spsc::lamport::Receiver::recv_timeout_async) and
+//! [`send_timeout_async()`](crate::spsc::lamport::Sender::send_timeout_async) don't depend on a
+//! runtime: by default, their deadline is tracked by [`ThreadTimer`], a single background thread
+//! shared by the whole process and started on first use. Any other [`Timer`] can be plugged in
+//! with [`with_timer()`](crate::spsc::lamport::RecvTimeout::with_timer), such as `TokioTimer`
+//! with the `tokio-timer` feature.
+//!
+//! ```
+//! use std::time::Duration;
+//! use veloce::spsc::{RecvTimeoutError, lamport::channel};
+//!
+//! # futures::executor::block_on(async {
+//! let (_tx, mut rx) = channel::<u32, 4>();
+//! assert!(matches!(
+//!     rx.recv_timeout_async(Duration::from_millis(10)).await,
+//!     Err(RecvTimeoutError::Timeout)
+//! ));
+//! # });
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex, OnceLock, Weak},
+    task::{Context, Poll},
+    thread,
+    time::Instant,
+};
+
+use futures::task::AtomicWaker;
+
+/// Creates the futures that complete once a deadline is reached.
+pub trait Timer {
+    type Sleep: Future<Output = ()> + Unpin;
+
+    /// Returns a future completing once `deadline` is reached.
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep;
+}
+
+/// Wakes the sleeping tasks from a background thread, whatever their executor.
+///
+/// The thread is shared by every channel, and started on the first sleep that has to wait.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadTimer;
+
+impl Timer for ThreadTimer {
+    type Sleep = ThreadSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> ThreadSleep {
+        ThreadSleep {
+            deadline,
+            waker: None,
+        }
+    }
+}
+
+/// Future returned by [`ThreadTimer`].
+#[must_use = "futures do nothing unless polled"]
+pub struct ThreadSleep {
+    deadline: Instant,
+    /// Registered with the timer thread on the first poll that has to wait. The thread only
+    /// holds a weak reference: dropping the sleep cancels it.
+    waker: Option<Arc<AtomicWaker>>,
+}
+
+impl Future for ThreadSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        match &self.waker {
+            Some(waker) => waker.register(cx.waker()),
+            None => {
+                let waker = Arc::new(AtomicWaker::new());
+                waker.register(cx.waker());
+                TimerThread::get().schedule(self.deadline, Arc::downgrade(&waker));
+                self.waker = Some(waker);
+            }
+        }
+
+        // The deadline may have been reached while registering
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+/// Sleep registered with the timer thread.
+struct Entry {
+    deadline: Instant,
+    waker: Weak<AtomicWaker>,
+}
+
+/// Earliest deadline first in the max-heap.
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.deadline.cmp(&self.deadline)
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    }
+}
+
+impl Eq for Entry {}
+
+struct TimerThread {
+    entries: Mutex<BinaryHeap<Entry>>,
+    /// Notified when a sleep is scheduled before the earliest deadline.
+    scheduled: Condvar,
+}
+
+impl TimerThread {
+    /// Returns the timer thread, spawning it on the first call.
+    fn get() -> &'static Self {
+        static TIMER: OnceLock<&'static TimerThread> = OnceLock::new();
+
+        TIMER.get_or_init(|| {
+            let timer: &'static Self = Box::leak(Box::new(Self {
+                entries: Mutex::new(BinaryHeap::new()),
+                scheduled: Condvar::new(),
+            }));
+            thread::Builder::new()
+                .name("veloce-timer".into())
+                .spawn(move || timer.run())
+                .expect("failed to spawn the timer thread");
+            timer
+        })
+    }
+
+    fn schedule(&self, deadline: Instant, waker: Weak<AtomicWaker>) {
+        let mut entries = self.entries.lock().unwrap();
+        // Before growing the heap, sweep the sleeps dropped before their deadline
+        if entries.len() == entries.capacity() {
+            entries.retain(|entry| entry.waker.strong_count() > 0);
+        }
+        let earliest = entries.peek().is_none_or(|first| deadline < first.deadline);
+        entries.push(Entry { deadline, waker });
+        drop(entries);
+
+        if earliest {
+            self.scheduled.notify_one();
+        }
+    }
+
+    /// Wakes the sleeps as their deadline is reached, waiting for the earliest one in between.
+    fn run(&self) -> ! {
+        let mut entries = self.entries.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            while let Some(first) = entries.peek() {
+                if first.deadline > now {
+                    break;
+                }
+                let expired = entries.pop().unwrap();
+                // Not upgradable if the sleep has been dropped
+                if let Some(waker) = expired.waker.upgrade() {
+                    waker.wake();
+                }
+            }
+
+            entries = match entries.peek() {
+                Some(first) => {
+                    let timeout = first.deadline - now;
+                    self.scheduled.wait_timeout(entries, timeout).unwrap().0
+                }
+                None => self.scheduled.wait(entries).unwrap(),
+            };
+        }
+    }
+}
+
+/// Sleeps on the timer of the tokio runtime, which must be running with the time driver
+/// enabled.
+#[cfg(all(feature = "tokio-timer", not(loom)))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioTimer;
+
+#[cfg(all(feature = "tokio-timer", not(loom)))]
+impl Timer for TokioTimer {
+    type Sleep = Pin<Box<tokio::time::Sleep>>;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Sleeps complete in deadline order, whichever the order they were scheduled in
+    #[test]
+    fn test_thread_timer() {
+        use futures::executor::block_on;
+
+        let start = Instant::now();
+        let late = ThreadTimer.sleep_until(start + Duration::from_millis(40));
+        block_on(ThreadTimer.sleep_until(start + Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        block_on(late);
+        assert!(start.elapsed() >= Duration::from_millis(40));
+
+        // Past deadlines complete right away
+        block_on(ThreadTimer.sleep_until(start));
+    }
+
+    /// Dropped sleeps are skipped by the timer thread
+    #[test]
+    fn test_thread_timer_cancel() {
+        use futures::{FutureExt, executor::block_on};
+
+        let start = Instant::now();
+        let mut cancelled = ThreadTimer.sleep_until(start + Duration::from_millis(10));
+        assert!(
+            cancelled
+                .poll_unpin(&mut Context::from_waker(futures::task::noop_waker_ref()))
+                .is_pending()
+        );
+        drop(cancelled);
+
+        block_on(ThreadTimer.sleep_until(start + Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,172 @@
+//! Operations shared by the halves of the `lamport` and `vyukov` channels.
+
+use crate::spsc::{TryRecvError, TrySendErr};
+
+/// Producer half of a SPSC channel.
+///
+/// Both flavors have the same method sets: [`SpscSender`] and [`SpscReceiver`] let code be
+/// written once and switch flavors with a type parameter, e.g. to compare them on a real
+/// workload. The inherent methods stay the fast path: the traits only forward to them.
+///
+/// ```
+/// use veloce::spsc::{SpscReceiver, SpscSender, lamport, vyukov};
+///
+/// fn pipeline<S, R>((mut tx, mut rx): (S, R)) -> u64
+/// where
+///     S: SpscSender<Item = u64> + Send,
+///     R: SpscReceiver<Item = u64>,
+/// {
+///     std::thread::scope(|s| {
+///         s.spawn(move || {
+///             for i in 0..100 {
+///                 tx.send_spin(i).unwrap();
+///             }
+///         });
+///
+///         let mut sum = 0;
+///         while let Ok(value) = rx.recv_spin() {
+///             sum += value;
+///         }
+///         sum
+///     })
+/// }
+///
+/// assert_eq!(pipeline(lamport::channel::<u64, 8>()), 4950);
+/// assert_eq!(pipeline(vyukov::channel::<u64, 8>()), 4950);
+/// ```
+pub trait SpscSender {
+    type Item;
+
+    /// Pushes a value if there is a free slot.
+    fn try_send(&mut self, value: Self::Item) -> Result<(), TrySendErr<Self::Item>>;
+
+    /// Pushes a value, busy-spinning while the channel is full.
+    fn send_spin(&mut self, value: Self::Item) -> Result<(), TrySendErr<Self::Item>>;
+
+    /// Number of slots of the channel.
+    fn capacity(&self) -> usize;
+
+    /// True once the receiver has been dropped.
+    fn is_closed(&self) -> bool;
+}
+
+/// Consumer half of a SPSC channel.
+pub trait SpscReceiver {
+    type Item;
+
+    /// Iterator returned by [`drain()`](Self::drain).
+    type Drain<'a>: Iterator<Item = Self::Item>
+    where
+        Self: 'a;
+
+    /// Pops a value if one is buffered.
+    fn try_recv(&mut self) -> Result<Self::Item, TryRecvError>;
+
+    /// Pops a value, busy-spinning while the channel is empty.
+    fn recv_spin(&mut self) -> Result<Self::Item, TryRecvError>;
+
+    /// Moves up to `max` buffered values out of the channel.
+    fn drain(&mut self, max: usize) -> Self::Drain<'_>;
+
+    /// Number of slots of the channel.
+    fn capacity(&self) -> usize;
+
+    /// True once the sender has been dropped.
+    fn is_closed(&self) -> bool;
+
+    /// Number of buffered values.
+    fn len(&self) -> usize;
+
+    /// True if no value is buffered.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Producer half of a SPSC channel that can also wait asynchronously.
+///
+/// Separate from [`SpscSender`], so that enabling the `async` feature only adds traits.
+#[cfg(feature = "async")]
+pub trait SpscAsyncSender: SpscSender {
+    /// Future returned by [`send()`](Self::send).
+    type SendFuture<'a>: Future<Output = Result<(), TrySendErr<Self::Item>>>
+    where
+        Self: 'a;
+
+    /// Pushes a value, waiting for a free slot asynchronously.
+    fn send(&mut self, value: Self::Item) -> Self::SendFuture<'_>;
+}
+
+/// Consumer half of a SPSC channel that can also wait asynchronously.
+#[cfg(feature = "async")]
+pub trait SpscAsyncReceiver: SpscReceiver {
+    /// Future returned by [`recv()`](Self::recv).
+    type RecvFuture<'a>: Future<Output = Result<Self::Item, TryRecvError>>
+    where
+        Self: 'a;
+
+    /// Pops a value, waiting for one asynchronously.
+    fn recv(&mut self) -> Self::RecvFuture<'_>;
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use super::*;
+    use crate::spsc::{lamport, vyukov};
+
+    fn exchange<S, R>((mut tx, mut rx): (S, R))
+    where
+        S: SpscSender<Item = i32>,
+        R: SpscReceiver<Item = i32>,
+    {
+        assert_eq!(tx.capacity(), 4);
+        assert_eq!(rx.capacity(), 4);
+        assert!(rx.is_empty());
+
+        for i in 0..4 {
+            tx.try_send(i).unwrap();
+        }
+        assert!(matches!(tx.try_send(4), Err(TrySendErr::Full(4))));
+        assert_eq!(rx.len(), 4);
+
+        assert_eq!(rx.try_recv().unwrap(), 0);
+        assert_eq!(rx.drain(2).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(rx.recv_spin().unwrap(), 3);
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+
+        drop(rx);
+        assert!(tx.is_closed());
+        assert!(matches!(tx.send_spin(5), Err(TrySendErr::Disconnected(5))));
+    }
+
+    /// Both flavors behave the same through the traits
+    #[test]
+    fn test_traits() {
+        exchange(lamport::channel::<i32, 4>());
+        exchange(vyukov::channel::<i32, 4>());
+    }
+
+    #[cfg(feature = "async")]
+    async fn exchange_async<S, R>((mut tx, mut rx): (S, R))
+    where
+        S: SpscAsyncSender<Item = i32>,
+        R: SpscAsyncReceiver<Item = i32>,
+    {
+        tx.send(1).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap(), 1);
+
+        drop(tx);
+        assert!(rx.is_closed());
+        assert!(matches!(rx.recv().await, Err(TryRecvError::Disconnected)));
+    }
+
+    /// The futures of both flavors are reachable through the traits
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_traits_async() {
+        use futures::executor::block_on;
+
+        block_on(exchange_async(lamport::channel::<i32, 4>()));
+        block_on(exchange_async(vyukov::channel::<i32, 4>()));
+    }
+}
@@ -1,43 +1,57 @@
-use std::sync::{
+use crate::sync::{
     Arc,
     atomic::{AtomicBool, Ordering},
 };
+use std::time::Instant;
 
 use crossbeam_utils::CachePadded;
 
 use super::{receiver::Receiver, sender::Sender, slot::Slot};
-use crate::ring::RingBuffer;
+use crate::ring::{DYNAMIC, RingBuffer};
 
-#[cfg(feature = "async")]
-use crate::spsc::r#async::Wakers;
+use crate::spsc::waiters::Waiters;
 #[cfg(feature = "async")]
 use std::task::Waker;
 
 pub(super) struct Channel<T, const N: usize> {
     pub(super) buffer: RingBuffer<Slot<T>, N>,
     pub(super) closed: CachePadded<AtomicBool>,
-
-    #[cfg(feature = "async")]
-    wakers: Wakers,
+    waiters: Waiters,
 }
 
 impl<T, const N: usize> Default for Channel<T, N> {
     fn default() -> Self {
-        let buffer = RingBuffer::default();
+        Self::new(RingBuffer::default())
+    }
+}
+
+impl<T> Channel<T, DYNAMIC> {
+    pub(super) fn with_capacity(capacity: usize) -> Self {
+        Self::new(RingBuffer::<Slot<T>, DYNAMIC>::with_capacity(capacity))
+    }
+
+    pub(super) fn with_exact_capacity(capacity: usize) -> Self {
+        Self::new(RingBuffer::<Slot<T>, DYNAMIC>::with_exact_capacity(
+            capacity,
+        ))
+    }
+}
+
+impl<T, const N: usize> Channel<T, N> {
+    pub(super) fn default_exact() -> Self {
+        Self::new(RingBuffer::<Slot<T>, N>::default_exact())
+    }
+
+    fn new(buffer: RingBuffer<Slot<T>, N>) -> Self {
+        assert!(buffer.capacity() > 1, "capacity must be at least 2");
         let closed = CachePadded::new(AtomicBool::new(false));
-        #[cfg(feature = "async")]
-        let wakers = Wakers::default();
+        let waiters = Waiters::default();
         Self {
             buffer,
             closed,
-            #[cfg(feature = "async")]
-            wakers,
+            waiters,
         }
     }
-}
-
-impl<T, const N: usize> Channel<T, N> {
-    const MASK: usize = N - 1;
 
     pub fn split(self) -> (Sender<T, N>, Receiver<T, N>) {
         let inner = Arc::new(self);
@@ -46,29 +60,66 @@ impl<T, const N: usize> Channel<T, N> {
         (tx, rx)
     }
 
+    /// Acquire: pairs with the Release store on drop, so that everything the dropped
+    /// half did before dropping is visible once `closed` is observed
     #[inline]
     pub fn is_closed(&self) -> bool {
-        self.closed.load(Ordering::Relaxed)
+        self.closed.load(Ordering::Acquire)
+    }
+
+    pub(super) fn park_sender(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_sender(deadline, ready);
+    }
+
+    pub(super) fn park_receiver(&self, deadline: Option<Instant>, ready: impl Fn() -> bool) {
+        self.waiters.park_receiver(deadline, ready);
+    }
+
+    /// Unparks or wakes the sender, if it waits: see [`Waiters`].
+    pub(super) fn notify_sender(&self) {
+        self.waiters.notify_sender();
+    }
+
+    /// Unparks or wakes the receiver, if it waits: see [`Waiters`].
+    pub(super) fn notify_receiver(&self) {
+        self.waiters.notify_receiver();
+    }
+
+    /// Unparks or wakes the sender, if it waits for `free(watermark)` slots.
+    #[inline]
+    pub(super) fn notify_sender_at(&self, free: impl FnOnce(usize) -> bool) {
+        self.waiters.notify_sender_at(free);
+    }
+
+    /// Unparks or wakes the receiver, if it waits for `buffered(watermark)` values.
+    #[inline]
+    pub(super) fn notify_receiver_at(&self, buffered: impl FnOnce(usize) -> bool) {
+        self.waiters.notify_receiver_at(buffered);
+    }
+
+    #[cfg(feature = "async")]
+    pub(super) fn set_sender_watermark(&self, low: usize) {
+        self.waiters.set_sender_watermark(low);
     }
 
     #[cfg(feature = "async")]
-    pub(super) fn wake_sender(&self) {
-        self.wakers.wake_sender()
+    pub(super) fn set_receiver_watermark(&self, high: usize) {
+        self.waiters.set_receiver_watermark(high);
     }
 
     #[cfg(feature = "async")]
-    pub(super) fn wake_receiver(&self) {
-        self.wakers.wake_receiver()
+    pub(super) fn set_receiver_lingered(&self, lingered: bool) {
+        self.waiters.set_receiver_lingered(lingered);
     }
 
     #[cfg(feature = "async")]
     pub(super) fn register_sender_waker(&self, waker: &Waker) {
-        self.wakers.register_sender_waker(waker);
+        self.waiters.register_sender_waker(waker);
     }
 
     #[cfg(feature = "async")]
     pub(super) fn register_receiver_waker(&self, waker: &Waker) {
-        self.wakers.register_receiver_waker(waker);
+        self.waiters.register_receiver_waker(waker);
     }
 }
 
@@ -84,15 +135,15 @@ impl<T, const N: usize> Drop for Channel<T, N> {
         // - Empty (ready for write): stamp % N == index
         // - Has data (ready for read): stamp % N == (index + 1) % N
         //
-        // Since N is a power of 2, we use bitwise AND for modulo.
-        for i in 0..N {
+        // The ring buffer maps sequences to indices, which is exactly the modulo.
+        for i in 0..self.buffer.capacity() {
             let slot = self.buffer.get(i);
             // Relaxed is fine here: we have exclusive access (&mut self) and
             // Arc's drop synchronized with all previous Release stores
             let stamp = slot.stamp.load(Ordering::Relaxed);
 
             // Check if slot has unread data: stamp % N == (index + 1) % N
-            if (stamp & Self::MASK) == ((i + 1) & Self::MASK) {
+            if self.buffer.index(stamp) == self.buffer.index(i + 1) {
                 // Safe: slot contains initialized data that was never consumed
                 unsafe { self.buffer.drop_in_place(i) };
             }
@@ -100,7 +151,7 @@ impl<T, const N: usize> Drop for Channel<T, N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(loom)))]
 mod tests {
     use super::*;
     use std::sync::atomic::AtomicUsize;
@@ -181,7 +232,7 @@ mod tests {
 
         // Scenario: write 3 items, read 1, drop channel → should drop 2 remaining
         {
-            let (tx, rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
+            let (mut tx, mut rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
 
             tx.try_send(DropCounter(drop_count.clone())).unwrap();
             tx.try_send(DropCounter(drop_count.clone())).unwrap();
@@ -216,7 +267,7 @@ mod tests {
         }
 
         {
-            let (tx, rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
+            let (mut tx, mut rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
 
             // First lap: write 4, read 4
             for _ in 0..4 {
@@ -321,7 +372,7 @@ mod tests {
         }
 
         {
-            let (tx, rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
+            let (mut tx, mut rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
 
             // First lap: write 4, read 4
             for _ in 0..4 {
@@ -361,7 +412,7 @@ mod tests {
         }
 
         {
-            let (tx, rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
+            let (mut tx, rx) = crate::spsc::vyukov::channel::<DropCounter, 4>();
 
             // Fill the buffer completely
             for _ in 0..4 {
@@ -25,6 +25,13 @@
 //! The sender and receiver maintain **local cursors** (non-atomic) and synchronize
 //! exclusively through per-slot stamps.
 //!
+//! `N` must be a power of 2, so that slots are found with a mask. [`channel_exact()`] lifts
+//! this requirement: cursors and stamps then wrap at `2 * N`, which keeps the stamps of a
+//! slot distinct across two consecutive laps.
+//!
+//! `N` must also be at least 2: with a single slot, `tail + 1` and `head + N` are the same
+//! stamp, and the sender couldn't tell a slot holding data from a slot ready for write.
+//!
 //! ## Synchronization
 //!
 //! No locks or OS primitives are used. Synchronization relies on:
@@ -45,44 +52,142 @@
 //! [`CachePadded`](crossbeam_utils::CachePadded) to prevent false sharing
 //! of their local cursors with the shared channel pointer.
 //!
+//! ## Runtime Capacity
+//!
+//! The capacity `N` is usually a compile-time constant, so that the ring buffer lives inline
+//! and the index mask is a constant. When the capacity is only known at runtime,
+//! [`channel_with_capacity()`] returns halves with `N = `[`DYNAMIC`]: the same types,
+//! backed by a heap-allocated buffer whose mask is computed at construction.
+//!
+//! ## Blocking Support
+//!
+//! [`send_blocking()`](Sender::send_blocking) and [`recv_blocking()`](Receiver::recv_blocking)
+//! park the OS thread instead of spinning, and are unparked by the other half when it makes
+//! progress or drops.
+//!
+//! Every successful operation notifies the other half, whether its thread is parked or its
+//! task awaits: a thread spinning on [`try_send()`](Sender::try_send) can feed a task awaiting
+//! [`recv()`](Receiver::recv), and the other way round. Nobody waiting, notifying costs a fence
+//! and two loads.
+//!
 //! ## Async Support
 //!
 //! With the `async` feature, [`send()`](Sender::send) and [`recv()`](Receiver::recv)
 //! return futures that poll the underlying lock-free operations. The futures
 //! themselves make no OS calls—whether the OS is involved depends on your runtime.
 //!
+//! The halves also plug into the `futures` combinators: the [`Receiver`] is a
+//! [`Stream`](futures::Stream) ending on disconnection, and the [`Sender`] is a
+//! [`Sink`](futures::Sink).
+//!
+//! `send()` and `recv()` borrow the half; [`send_owned()`](Sender::send_owned) and
+//! [`recv_owned()`](Receiver::recv_owned) take it by value and hand it back on completion,
+//! for `'static` futures that can be spawned or queued.
+//!
+//! Sending is not cancel-safe, as the future owns the value: in `select!` loops,
+//! [`reserve_async()`](Sender::reserve_async) waits for a free slot first, then the returned
+//! [`Permit`] sends without failing.
+//!
+//! [`recv_timeout_async()`](Receiver::recv_timeout_async) and
+//! [`send_timeout_async()`](Sender::send_timeout_async) give up after a while, on any executor:
+//! see [`timer`](crate::spsc::timer) for the timer tracking their deadline.
+//!
+//! For throughput-oriented pipelines, a task can be woken once per batch rather than once per
+//! value: [`set_high_watermark()`](Receiver::set_high_watermark) wakes the receiver once enough
+//! values are buffered (or after a linger), and [`set_low_watermark()`](Sender::set_low_watermark)
+//! wakes the sender once enough slots are free.
+//!
+//! ## Thread Safety
+//!
+//! Each half can be moved to another thread, but is not `Sync`: a half is used by one thread at
+//! a time, which is what makes the single-producer single-consumer protocol sound. Operations
+//! that move values in or out of the channel take `&mut self`, and sharing a half by reference
+//! across threads doesn't compile, even to call its `&self` methods:
+//!
+//!```compile_fail,E0277
+//! use veloce::spsc::vyukov::channel;
+//!
+//! let (_tx, rx) = channel::<i32, 4>();
+//! let rx = &rx;
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| rx.len());
+//!     s.spawn(|| rx.len());
+//! });
+//! ```
+//!
+//!```compile_fail,E0277
+//! use veloce::spsc::vyukov::channel;
+//!
+//! let (tx, _rx) = channel::<i32, 4>();
+//! let tx = &tx;
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| tx.is_closed());
+//!     s.spawn(|| tx.is_closed());
+//! });
+//! ```
+//!
 //! ## Example
 //!
 //!```rust
 //! use veloce::spsc::vyukov::channel4;
 //!
-//! let (tx, rx) = channel4::<i32>();  // Buffer size must be power of 2
+//! let (mut tx, mut rx) = channel4::<i32>();  // Buffer size must be power of 2
 //!
 //! tx.try_send(1).unwrap();
 //! tx.try_send(2).unwrap();
 //!
-//! assert_eq!(rx.try_recv().unwrap(), Some(1));
-//! assert_eq!(rx.try_recv().unwrap(), Some(2));
-//! assert_eq!(rx.try_recv().unwrap(), None);  // Empty
+//! assert_eq!(rx.try_recv().unwrap(), 1);
+//! assert_eq!(rx.try_recv().unwrap(), 2);
+//! assert!(rx.try_recv().is_err());  // Empty
 //! ```
 
 mod channel;
 mod receiver;
 mod sender;
 
+pub use crate::ring::DYNAMIC;
 use channel::Channel;
+pub use receiver::{Buffered, Drain, ReadGuard, Receiver};
 #[cfg(feature = "async")]
-pub use receiver::RecvFuture;
-pub use receiver::{Drain, Receiver};
+pub use receiver::{RecvFuture, RecvManyFuture, RecvOwned, RecvTimeout};
 #[cfg(feature = "async")]
-pub use sender::SendFuture;
-pub use sender::Sender;
+pub use sender::{
+    ClosedFuture, FlushFuture, Permit, ReserveFuture, SendFuture, SendOwned, SendTimeout,
+};
+pub use sender::{Fill, Sender, WriteSlot};
 mod slot;
 
 pub fn channel<T, const N: usize>() -> (Sender<T, N>, Receiver<T, N>) {
     Channel::default().split()
 }
 
+/// Creates a channel whose capacity is chosen at runtime.
+///
+/// The slots are allocated on the heap and `capacity` must be a power of 2.
+pub fn channel_with_capacity<T>(capacity: usize) -> (Sender<T, DYNAMIC>, Receiver<T, DYNAMIC>) {
+    Channel::with_capacity(capacity).split()
+}
+
+/// Creates a channel of exactly `N` slots, where `N` doesn't need to be a power of 2.
+///
+/// When `N` is not a power of 2, cursors and stamps wrap at `2 * N` and are mapped to slots with
+/// a conditional subtraction instead of a mask: a few more instructions per operation, in exchange
+/// for not rounding the buffer up to the next power of 2.
+pub fn channel_exact<T, const N: usize>() -> (Sender<T, N>, Receiver<T, N>) {
+    Channel::default_exact().split()
+}
+
+/// Creates a channel of exactly `capacity` slots, chosen at runtime.
+///
+/// See [`channel_exact()`] for the trade-off of capacities that are not a power of 2.
+pub fn channel_with_exact_capacity<T>(
+    capacity: usize,
+) -> (Sender<T, DYNAMIC>, Receiver<T, DYNAMIC>) {
+    Channel::with_exact_capacity(capacity).split()
+}
+
 /// Generates type aliases for common buffer sizes.
 ///
 /// Creates types like `Sender2<T>`, `channel16<T>`, `Receiver64<T>`, etc.
@@ -97,6 +202,18 @@ macro_rules! define_size_aliases {
                 pub type [<SendFuture $n>]<'a, T> = SendFuture<'a, T, $n>;
                 #[cfg(feature = "async")]
                 pub type [<RecvFuture $n>]<'a, T> = RecvFuture<'a, T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<RecvManyFuture $n>]<'a, T> = RecvManyFuture<'a, T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<SendOwned $n>]<T> = SendOwned<T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<RecvOwned $n>]<T> = RecvOwned<T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<ReserveFuture $n>]<'a, T> = ReserveFuture<'a, T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<RecvTimeout $n>]<'a, T> = RecvTimeout<'a, T, $n>;
+                #[cfg(feature = "async")]
+                pub type [<SendTimeout $n>]<'a, T> = SendTimeout<'a, T, $n>;
 
                 /// Creates a channel with specific buffer size .
                 pub fn [<channel $n>]<T>() -> ([<Sender $n>]<T>, [<Receiver $n>]<T>) {
@@ -110,19 +227,49 @@ macro_rules! define_size_aliases {
 // Generate aliases for powers of 2
 define_size_aliases!(2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192);
 
-#[cfg(test)]
+#[cfg(all(test, not(loom)))]
 mod tests {
-    use crate::spsc::TrySendErr;
+    use crate::spsc::{
+        RecvTimeoutError, SendTimeoutError, TryRecvError, TrySendErr,
+        wait::{Backoff, BusySpin, Sleep, SpinThenPark, SpinThenYield, WaitStrategy},
+    };
     use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::{sync::Arc, thread::sleep, time::Duration};
+    use std::{
+        sync::Arc,
+        thread::sleep,
+        time::{Duration, Instant},
+    };
 
     use super::*;
 
+    /// Waker counting how many times it has been woken
+    #[cfg(feature = "async")]
+    struct WakeCounter(AtomicUsize);
+
+    #[cfg(feature = "async")]
+    impl WakeCounter {
+        fn waker() -> (Arc<Self>, std::task::Waker) {
+            let counter = Arc::new(Self(AtomicUsize::new(0)));
+            (counter.clone(), futures::task::waker(counter))
+        }
+
+        fn wakes(&self) -> usize {
+            self.0.load(Ordering::SeqCst)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    impl futures::task::ArcWake for WakeCounter {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
     /// When buffer is full, sender shouldn't be capable to push a new value
     #[test]
     fn test_full() {
         const N: usize = 4;
-        let (tx, _rx) = channel::<(), N>();
+        let (mut tx, _rx) = channel::<(), N>();
         for _ in 0..N {
             tx.try_send(()).unwrap();
         }
@@ -148,7 +295,7 @@ mod tests {
     #[test]
     fn test_proper_consumption() {
         const N: usize = 4;
-        let (tx, rx) = channel::<(), N>();
+        let (mut tx, mut rx) = channel::<(), N>();
         for _ in 0..N {
             tx.try_send(()).unwrap();
         }
@@ -165,7 +312,7 @@ mod tests {
     /// Inter-thread communication check
     #[test]
     fn test_channel() {
-        let (tx, rx) = channel::<_, 2>();
+        let (mut tx, mut rx) = channel::<_, 2>();
 
         let words = [
             String::from("hello"),
@@ -207,7 +354,7 @@ mod tests {
         let dropper = DropCounter(inner.clone());
 
         {
-            let (tx, rx) = channel::<DropCounter, 4>();
+            let (mut tx, rx) = channel::<DropCounter, 4>();
             tx.try_send(dropper.clone()).unwrap();
             tx.try_send(dropper).unwrap();
             drop(rx);
@@ -216,171 +363,1899 @@ mod tests {
         assert_eq!(inner.load(Ordering::SeqCst), 2);
     }
 
-    /// Test the async strategy
-    #[cfg(feature = "async")]
-    #[tokio::test]
-    async fn test_async_channel() {
-        let (tx, rx) = channel::<i32, 8>();
+    /// A channel sized at runtime behaves like a channel sized at compile time
+    #[test]
+    fn test_with_capacity() {
+        let inner = Arc::new(AtomicUsize::new(0));
 
-        let handle = tokio::spawn(async move {
-            for i in 0..10 {
-                tx.send(i).await.unwrap();
-            }
-        });
+        let (mut tx, mut rx) = channel_with_capacity::<DropCounter>(4);
+        assert_eq!(tx.capacity(), 4);
+        assert_eq!(rx.capacity(), 4);
 
-        for i in 0..10 {
-            assert_eq!(rx.recv().await.unwrap(), i);
+        for _ in 0..4 {
+            tx.try_send(DropCounter(inner.clone())).unwrap();
         }
+        assert!(matches!(
+            tx.try_send(DropCounter(inner.clone())),
+            Err(TrySendErr::Full(..))
+        ));
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
 
-        handle.await.unwrap();
-    }
-
-    #[test]
-    fn test_drain_all() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        for i in 0..5 {
-            tx.try_send(i).unwrap();
+        // wrap around the buffer a few times
+        for _ in 0..10 {
+            rx.try_recv().unwrap();
+            tx.try_send(DropCounter(inner.clone())).unwrap();
         }
+        assert_eq!(rx.drain(2).count(), 2);
+        assert_eq!(inner.load(Ordering::SeqCst), 13);
 
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![0, 1, 2, 3, 4]);
-        assert!(rx.is_empty());
+        // the 2 unread items are dropped with the channel
+        drop(tx);
+        drop(rx);
+        assert_eq!(inner.load(Ordering::SeqCst), 15);
     }
 
+    /// Runtime capacity must be a power of two
     #[test]
-    fn test_drain_with_max() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        for i in 0..5 {
-            tx.try_send(i).unwrap();
-        }
+    #[should_panic]
+    fn test_with_capacity_panics() {
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
 
-        // Drain only 3 of 5
-        let items: Vec<_> = rx.drain(3).collect();
-        assert_eq!(items, vec![0, 1, 2]);
-        assert_eq!(rx.len(), 2);
+        let _ = channel_with_capacity::<()>(1000);
 
-        // Drain remaining
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![3, 4]);
+        std::panic::set_hook(prev);
     }
 
+    /// A single slot can't tell written and read stamps apart
     #[test]
-    fn test_drain_empty() {
-        let (_tx, mut rx) = channel::<i32, 8>();
-        let items: Vec<_> = rx.drain(100).collect();
-        assert!(items.is_empty());
+    #[should_panic]
+    fn test_capacity_one_panics() {
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let _ = channel::<(), 1>();
+
+        std::panic::set_hook(prev);
     }
 
+    /// Capacities that are not a power of 2 are honoured exactly, across many laps
     #[test]
-    fn test_drain_partial_consume() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        for i in 0..5 {
-            tx.try_send(i).unwrap();
+    fn test_exact() {
+        const N: usize = 3;
+        let inner = Arc::new(AtomicUsize::new(0));
+
+        let (mut tx, mut rx) = channel_exact::<DropCounter, N>();
+        assert_eq!(tx.capacity(), N);
+
+        for _ in 0..N {
+            tx.try_send(DropCounter(inner.clone())).unwrap();
         }
+        assert!(matches!(
+            tx.try_send(DropCounter(inner.clone())),
+            Err(TrySendErr::Full(..))
+        ));
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+        assert_eq!(rx.len(), N);
 
-        // Consume only 2 items via early break
-        {
-            let mut drain = rx.drain(usize::MAX);
-            assert_eq!(drain.next(), Some(0));
-            assert_eq!(drain.next(), Some(1));
-            // drop drain here - should commit 2 items
+        // sequences wrap at 2 * N: go around a few times
+        for _ in 0..5 * N {
+            rx.try_recv().unwrap();
+            tx.try_send(DropCounter(inner.clone())).unwrap();
+            assert_eq!(rx.len(), N);
         }
+        assert_eq!(rx.drain(2).count(), 2);
+        assert_eq!(rx.len(), 1);
+        assert_eq!(inner.load(Ordering::SeqCst), 1 + 5 * N + 2);
 
-        // Remaining 3 items should still be there
-        assert_eq!(rx.len(), 3);
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![2, 3, 4]);
+        // the unread item is dropped with the channel
+        drop(tx);
+        drop(rx);
+        assert_eq!(inner.load(Ordering::SeqCst), 1 + 5 * N + 3);
     }
 
+    /// A runtime capacity that is not a power of 2 preserves ordering between threads
     #[test]
-    fn test_drain_remaining() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        for i in 0..5 {
-            tx.try_send(i).unwrap();
+    fn test_with_exact_capacity() {
+        const COUNT: usize = 100_000;
+        let (mut tx, mut rx) = channel_with_exact_capacity::<usize>(1000);
+        assert_eq!(rx.capacity(), 1000);
+
+        let producer = std::thread::spawn(move || {
+            for i in 0..COUNT {
+                tx.send_spin(i).unwrap();
+            }
+        });
+        for i in 0..COUNT {
+            assert_eq!(rx.recv_spin().unwrap(), i);
         }
+        producer.join().unwrap();
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Disconnected)));
+    }
 
-        // Note: remaining() is now the max allowed, not exact count
-        let mut drain = rx.drain(usize::MAX);
-        assert_eq!(drain.remaining(), usize::MAX);
+    /// Both halves park while the channel is full/empty and are unparked by the other half
+    #[test]
+    fn test_blocking() {
+        let (mut tx, mut rx) = channel::<usize, 2>();
 
-        drain.next();
-        assert_eq!(drain.remaining(), usize::MAX - 1);
+        let handle = std::thread::spawn(move || {
+            for i in 0..1000 {
+                tx.send_blocking(i).unwrap();
+            }
+        });
 
-        drain.next();
-        drain.next();
-        assert_eq!(drain.remaining(), usize::MAX - 3);
+        for i in 0..1000 {
+            assert_eq!(rx.recv_blocking().unwrap(), i);
+        }
+
+        handle.join().unwrap();
+        assert!(matches!(
+            rx.recv_blocking(),
+            Err(TryRecvError::Disconnected)
+        ));
     }
 
+    /// A parked receiver wakes up when the sender drops
     #[test]
-    fn test_drain_after_sender_dropped() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        tx.try_send(1).unwrap();
-        tx.try_send(2).unwrap();
-        drop(tx);
+    fn test_blocking_recv_disconnected() {
+        let (tx, mut rx) = channel::<(), 2>();
 
-        assert!(rx.is_closed());
+        let handle = std::thread::spawn(move || {
+            sleep(Duration::from_millis(10));
+            drop(tx);
+        });
 
-        // Should still drain buffered items
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![1, 2]);
+        assert!(matches!(
+            rx.recv_blocking(),
+            Err(TryRecvError::Disconnected)
+        ));
+        handle.join().unwrap();
     }
 
+    /// A parked sender wakes up when the receiver drops
     #[test]
-    fn test_drain_non_copy_types() {
-        let (tx, mut rx) = channel::<String, 4>();
-        tx.try_send("hello".into()).unwrap();
-        tx.try_send("world".into()).unwrap();
+    fn test_blocking_send_disconnected() {
+        let (mut tx, rx) = channel::<(), 2>();
+        tx.try_send(()).unwrap();
+        tx.try_send(()).unwrap();
 
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec!["hello", "world"]);
+        let handle = std::thread::spawn(move || {
+            sleep(Duration::from_millis(10));
+            drop(rx);
+        });
+
+        assert!(matches!(
+            tx.send_blocking(()),
+            Err(TrySendErr::Disconnected(()))
+        ));
+        handle.join().unwrap();
     }
 
+    /// Deadline-bounded operations time out and give the value back
     #[test]
-    fn test_drain_multiple_rounds() {
-        let (tx, mut rx) = channel::<i32, 4>();
+    fn test_timeout() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        assert!(matches!(
+            rx.recv_timeout(Duration::from_millis(10)),
+            Err(RecvTimeoutError::Timeout)
+        ));
 
-        // Round 1
         tx.try_send(1).unwrap();
         tx.try_send(2).unwrap();
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![1, 2]);
+        let err = tx
+            .send_timeout(3, Duration::from_millis(10))
+            .expect_err("should time out");
+        assert!(matches!(err, SendTimeoutError::Timeout(3)));
 
-        // Round 2 - buffer slots should be reusable
-        tx.try_send(3).unwrap();
-        tx.try_send(4).unwrap();
-        tx.try_send(5).unwrap();
-        let items: Vec<_> = rx.drain(usize::MAX).collect();
-        assert_eq!(items, vec![3, 4, 5]);
+        assert_eq!(rx.recv_timeout(Duration::from_millis(10)).unwrap(), 1);
+        tx.send_deadline(3, Instant::now() + Duration::from_millis(10))
+            .unwrap();
     }
 
+    /// A value pushed while the receiver is parked is received before the deadline
     #[test]
-    fn test_drain_max_zero() {
-        let (tx, mut rx) = channel::<i32, 8>();
-        tx.try_send(1).unwrap();
+    fn test_timeout_wakeup() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
 
-        // max=0 should yield nothing
-        let items: Vec<_> = rx.drain(0).collect();
-        assert!(items.is_empty());
+        let handle = std::thread::spawn(move || {
+            sleep(Duration::from_millis(10));
+            tx.send_blocking(1).unwrap();
+        });
 
-        // Item should still be there
-        assert_eq!(rx.len(), 1);
+        assert_eq!(rx.recv_timeout(Duration::from_secs(10)).unwrap(), 1);
+        handle.join().unwrap();
+
+        assert!(matches!(
+            rx.recv_deadline(Instant::now() + Duration::from_secs(10)),
+            Err(RecvTimeoutError::Disconnected)
+        ));
     }
 
+    /// Every built-in wait strategy drives a full exchange between two threads
     #[test]
-    fn test_drain_is_closed() {
-        let (tx, mut rx) = channel::<i32, 8>();
+    fn test_wait_strategies() {
+        fn exchange<W: WaitStrategy + Send + 'static>(mut tx_strategy: W, mut rx_strategy: W) {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let handle = std::thread::spawn(move || {
+                for i in 0..200 {
+                    tx.send_with(i, &mut tx_strategy).unwrap();
+                }
+            });
+
+            for i in 0..200 {
+                assert_eq!(rx.recv_with(&mut rx_strategy).unwrap(), i);
+            }
+
+            handle.join().unwrap();
+            assert!(matches!(
+                rx.recv_with(&mut rx_strategy),
+                Err(TryRecvError::Disconnected)
+            ));
+        }
+
+        exchange(BusySpin, BusySpin);
+        exchange(Backoff::default(), Backoff::default());
+        exchange(SpinThenYield::new(8), SpinThenYield::new(8));
+        exchange(SpinThenPark::new(8), SpinThenPark::new(8));
+        exchange(
+            Sleep(Duration::from_micros(10)),
+            Sleep(Duration::from_micros(10)),
+        );
+    }
+
+    /// Test the async strategy
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_channel() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+
+        let handle = tokio::spawn(async move {
+            for i in 0..10 {
+                tx.send(i).await.unwrap();
+            }
+        });
+
+        for i in 0..10 {
+            assert_eq!(rx.recv().await.unwrap(), i);
+        }
+
+        handle.await.unwrap();
+    }
+
+    /// The receiver is a stream ending on disconnection, fed by the sender through its sink
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_stream_sink() {
+        use futures::{StreamExt, stream};
+
+        let (tx, rx) = channel::<i32, 4>();
+
+        let handle = tokio::spawn(stream::iter(0..100).map(Ok).forward(tx));
+
+        let items: Vec<_> = rx.collect().await;
+        assert_eq!(items, (0..100).collect::<Vec<_>>());
+        handle.await.unwrap().unwrap();
+    }
+
+    /// Closing the sink disconnects the receiver once the values in flight are consumed
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_sink_close() {
+        use futures::{SinkExt, StreamExt};
+
+        let (mut tx, mut rx) = channel::<i32, 4>();
+
+        SinkExt::send(&mut tx, 1).await.unwrap();
+        tx.close().await.unwrap();
+        assert!(SinkExt::send(&mut tx, 2).await.is_err());
+
+        assert_eq!(rx.next().await, Some(1));
+        assert_eq!(rx.next().await, None);
+    }
+
+    /// The sink errors once the receiver drops, also while waiting for a free slot
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_sink_disconnected() {
+        use futures::SinkExt;
+
+        let (mut tx, rx) = channel::<i32, 2>();
         tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
 
-        {
-            let drain = rx.drain(usize::MAX);
-            assert!(!drain.is_closed());
+        let handle = tokio::spawn(async move {
+            tokio::task::yield_now().await;
+            drop(rx);
+        });
+
+        assert!(SinkExt::send(&mut tx, 3).await.is_err());
+        handle.await.unwrap();
+    }
+
+    /// A hand-written future polls several channels through the low-level poll methods
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_poll_methods() {
+        use std::{future::poll_fn, task::Poll};
+
+        let (mut tx1, mut rx1) = channel::<i32, 2>();
+        let (mut tx2, mut rx2) = channel::<i32, 2>();
+
+        let producer = tokio::spawn(async move {
+            let mut next = 0;
+            poll_fn(|cx| {
+                while next < 20 {
+                    let tx = if next % 2 == 0 { &mut tx1 } else { &mut tx2 };
+                    match tx.poll_ready(cx) {
+                        Poll::Ready(Ok(())) => tx.start_send(next).unwrap(),
+                        Poll::Ready(Err(_)) => panic!("receiver dropped"),
+                        Poll::Pending => return Poll::Pending,
+                    }
+                    next += 1;
+                }
+                Poll::Ready(())
+            })
+            .await;
+        });
+
+        let mut received = Vec::new();
+        let mut open = [true, true];
+        poll_fn(|cx| {
+            loop {
+                let mut pending = true;
+                for (rx, open) in [&mut rx1, &mut rx2].into_iter().zip(open.iter_mut()) {
+                    if !*open {
+                        continue;
+                    }
+                    match rx.poll_recv(cx) {
+                        Poll::Ready(Ok(v)) => {
+                            received.push(v);
+                            pending = false;
+                        }
+                        Poll::Ready(Err(_)) => *open = false,
+                        Poll::Pending => {}
+                    }
+                }
+                if open == [false, false] {
+                    return Poll::Ready(());
+                }
+                if pending {
+                    return Poll::Pending;
+                }
+            }
+        })
+        .await;
+
+        producer.await.unwrap();
+        received.sort();
+        assert_eq!(received, (0..20).collect::<Vec<_>>());
+    }
+
+    /// Owned futures are `'static`: pending receives of several channels are kept
+    /// in a `FuturesUnordered` and re-armed with the receiver they hand back
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_owned_futures() {
+        use futures::stream::{FuturesUnordered, StreamExt};
+
+        let mut pending = FuturesUnordered::new();
+        let mut producers = Vec::new();
+        for id in 0..3 {
+            let (mut tx, rx) = channel::<i32, 2>();
+            pending.push(rx.recv_owned());
+            producers.push(tokio::spawn(async move {
+                for i in 0..10 {
+                    let (sender, result) = tx.send_owned(id * 10 + i).await;
+                    result.unwrap();
+                    tx = sender;
+                }
+            }));
         }
 
-        drop(tx);
+        let mut received = Vec::new();
+        while let Some((rx, result)) = pending.next().await {
+            if let Ok(v) = result {
+                received.push(v);
+                pending.push(rx.recv_owned());
+            }
+        }
 
-        {
-            let drain = rx.drain(usize::MAX);
-            assert!(drain.is_closed());
+        for producer in producers {
+            producer.await.unwrap();
         }
+        received.sort();
+        assert_eq!(received, (0..30).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_send_owned_disconnected() {
+        let (tx, rx) = channel::<i32, 2>();
+        drop(rx);
+
+        let (tx, result) = tx.send_owned(1).await;
+        assert!(matches!(result, Err(TrySendErr::Disconnected(1))));
+        assert!(tx.is_closed());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_recv_many() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+
+        let handle = tokio::spawn(async move {
+            for i in 0..100 {
+                tx.send(i).await.unwrap();
+            }
+        });
+
+        let mut received = Vec::new();
+        loop {
+            match rx.recv_many(&mut received, 3).await {
+                Ok(count) => assert!((1..=3).contains(&count)),
+                Err(e) => {
+                    assert!(matches!(e, TryRecvError::Disconnected));
+                    break;
+                }
+            }
+        }
+        handle.await.unwrap();
+        assert_eq!(received, (0..100).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_recv_many_disconnected() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        drop(tx);
+
+        // Values sent before the disconnection are received first
+        let mut buffer = vec![0];
+        assert_eq!(rx.recv_many(&mut buffer, 0).await.unwrap(), 0);
+        assert_eq!(rx.recv_many(&mut buffer, 8).await.unwrap(), 2);
+        assert_eq!(buffer, [0, 1, 2]);
+        assert!(matches!(
+            rx.recv_many(&mut buffer, 8).await,
+            Err(TryRecvError::Disconnected)
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_reserve_permit() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        let handle = tokio::spawn(async move {
+            for i in 0..100 {
+                tx.reserve_async().await.unwrap().send(i);
+            }
+        });
+
+        for i in 0..100 {
+            assert_eq!(rx.recv().await.unwrap(), i);
+        }
+        handle.await.unwrap();
+    }
+
+    /// A reservation cancelled by `select!` loses nothing: the value is only handed
+    /// over once the slot is free
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_reserve_cancel() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+        tx.try_send(0).unwrap();
+        tx.try_send(1).unwrap();
+
+        let mut received = Vec::new();
+        let mut next = 2;
+        let mut cancelled = 0;
+        while next < 10 {
+            tokio::select! {
+                biased;
+                permit = tx.reserve_async() => {
+                    permit.unwrap().send(next);
+                    next += 1;
+                }
+                _ = tokio::task::yield_now() => {
+                    cancelled += 1;
+                    received.push(rx.recv().await.unwrap());
+                }
+            }
+        }
+        assert!(cancelled > 0);
+
+        // Dropping a permit gives the slot back
+        received.push(rx.recv().await.unwrap());
+        drop(tx.reserve_async().await.unwrap());
+        tx.reserve_async().await.unwrap().send(10);
+
+        drop(tx);
+        while let Ok(v) = rx.recv().await {
+            received.push(v);
+        }
+        assert_eq!(received, (0..=10).collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_reserve_disconnected() {
+        let (mut tx, rx) = channel::<i32, 2>();
+        let permit = tx.reserve_async().await.unwrap();
+        drop(rx);
+
+        // Sending through a permit can't fail, even after the receiver dropped
+        permit.send(1);
+        assert!(tx.reserve_async().await.is_err());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_closed() {
+        let (mut tx, rx) = channel::<i32, 2>();
+
+        let handle = tokio::spawn(async move {
+            tokio::task::yield_now().await;
+            drop(rx);
+        });
+
+        tx.closed().await;
+        assert!(tx.is_closed());
+        handle.await.unwrap();
+
+        // Already closed: resolves right away
+        tx.closed().await;
+    }
+
+    #[test]
+    fn test_closed_blocking() {
+        let (mut tx, rx) = channel::<i32, 2>();
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                sleep(Duration::from_millis(10));
+                drop(rx);
+            });
+            tx.closed_blocking();
+            assert!(tx.is_closed());
+        });
+
+        let (mut tx, rx) = channel::<i32, 2>();
+        std::thread::scope(|s| {
+            s.spawn(move || drop(rx));
+            tx.closed_spin();
+            assert!(tx.is_closed());
+        });
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_flush() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+
+        // Nothing sent: nothing to wait for
+        tx.flush().await.unwrap();
+
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert!(futures::poll!(tx.flush()).is_pending());
+        rx.try_recv().unwrap();
+        assert!(futures::poll!(tx.flush()).is_pending());
+        rx.try_recv().unwrap();
+        tx.flush().await.unwrap();
+
+        // The consumer wakes the flushing producer as it receives
+        let handle = tokio::spawn(async move {
+            for i in 0..100 {
+                assert_eq!(rx.recv().await.unwrap(), i);
+            }
+            rx
+        });
+        for i in 0..100 {
+            tx.send(i).await.unwrap();
+        }
+        tx.flush().await.unwrap();
+        let mut rx = handle.await.unwrap();
+        assert!(rx.try_recv().is_err());
+
+        // Dropped before consuming everything
+        tx.try_send(3).unwrap();
+        drop(rx);
+        assert!(tx.flush().await.is_err());
+    }
+
+    /// Sequences wrap at twice a capacity that is not a power of two
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_flush_exact() {
+        let (mut tx, mut rx) = channel_exact::<i32, 3>();
+        tx.flush().await.unwrap();
+
+        for i in 0..10 {
+            tx.try_send(i).unwrap();
+            tx.try_send(i).unwrap();
+            assert!(futures::poll!(tx.flush()).is_pending());
+            rx.try_recv().unwrap();
+            assert!(futures::poll!(tx.flush()).is_pending());
+            rx.try_recv().unwrap();
+            tx.flush().await.unwrap();
+        }
+    }
+
+    #[test]
+    fn test_flush_blocking() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+        let received = AtomicUsize::new(0);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for i in 0..100 {
+                    assert_eq!(rx.recv_blocking().unwrap(), i);
+                    received.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+            for i in 0..100 {
+                tx.send_blocking(i).unwrap();
+            }
+            tx.flush_blocking().unwrap();
+        });
+        assert_eq!(received.load(Ordering::Relaxed), 100);
+
+        tx.try_send(1).unwrap();
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                sleep(Duration::from_millis(10));
+                rx.try_recv().unwrap();
+            });
+            tx.flush_spin().unwrap();
+        });
+
+        tx.try_send(2).unwrap();
+        drop(rx);
+        assert!(tx.flush_blocking().is_err());
+        assert!(tx.flush_spin().is_err());
+    }
+
+    /// `try_send` unparks a receiver blocked in `recv_blocking`, and `try_recv` a sender blocked
+    /// in `send_blocking`
+    #[test]
+    fn test_try_ops_unpark_blocking() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for i in 0..100 {
+                    assert_eq!(rx.recv_blocking().unwrap(), i);
+                }
+            });
+            for i in 0..100 {
+                tx.send_spin(i).unwrap();
+            }
+        });
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for i in 0..100 {
+                    tx.send_blocking(i).unwrap();
+                }
+            });
+            for i in 0..100 {
+                loop {
+                    match rx.try_recv() {
+                        Ok(v) => break assert_eq!(v, i),
+                        Err(_) => sleep(Duration::from_micros(100)),
+                    }
+                }
+            }
+        });
+    }
+
+    /// A thread spinning on `send_spin` wakes the task awaiting `recv()`
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_sync_sender_async_receiver() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        let producer = std::thread::spawn(move || {
+            for i in 0..100 {
+                tx.send_spin(i).unwrap();
+            }
+        });
+
+        for i in 0..100 {
+            assert_eq!(rx.recv().await.unwrap(), i);
+        }
+        assert!(matches!(rx.recv().await, Err(TryRecvError::Disconnected)));
+        producer.join().unwrap();
+    }
+
+    /// A thread polling `try_recv` wakes the task awaiting `send()` on a full channel
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_async_sender_sync_receiver() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        let consumer = std::thread::spawn(move || {
+            let mut received = Vec::new();
+            loop {
+                match rx.try_recv() {
+                    Ok(v) => received.push(v),
+                    Err(TryRecvError::Empty) => sleep(Duration::from_micros(100)),
+                    Err(TryRecvError::Disconnected) => return received,
+                }
+            }
+        });
+
+        for i in 0..100 {
+            tx.send(i).await.unwrap();
+        }
+        drop(tx);
+        assert_eq!(consumer.join().unwrap(), (0..100).collect::<Vec<_>>());
+    }
+
+    /// A pending half is woken once; operations performed while nobody waits wake nobody
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_wake_only_waiters() {
+        use std::task::{Context, Poll};
+
+        let (counter, waker) = WakeCounter::waker();
+        let mut cx = Context::from_waker(&waker);
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert_eq!(counter.wakes(), 1);
+        assert!(matches!(rx.poll_recv(&mut cx), Poll::Ready(Ok(1))));
+        assert!(matches!(rx.poll_recv(&mut cx), Poll::Ready(Ok(2))));
+
+        while tx.try_send(3).is_ok() {}
+        assert!(tx.poll_ready(&mut cx).is_pending());
+        rx.try_recv().unwrap();
+        rx.try_recv().unwrap();
+        assert_eq!(counter.wakes(), 2);
+        assert!(matches!(tx.poll_ready(&mut cx), Poll::Ready(Ok(()))));
+    }
+
+    /// The receiver task is woken once the high watermark is reached, once the linger passed,
+    /// or when the sender flushes
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_high_watermark() {
+        use std::{
+            pin::pin,
+            task::{Context, Poll},
+        };
+
+        let (counter, waker) = WakeCounter::waker();
+        let mut cx = Context::from_waker(&waker);
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        let mut buffer = Vec::new();
+
+        rx.set_high_watermark(3, None);
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        tx.try_send(0).unwrap();
+        tx.try_send(1).unwrap();
+        assert_eq!(counter.wakes(), 0);
+        tx.try_send(2).unwrap();
+        assert_eq!(counter.wakes(), 1);
+        assert!(matches!(
+            rx.poll_recv_many(&mut cx, &mut buffer, 8),
+            Poll::Ready(Ok(3))
+        ));
+
+        // Flushing wakes the receiver below the watermark
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        tx.try_send(3).unwrap();
+        assert_eq!(counter.wakes(), 1);
+        {
+            let (_, sender_waker) = WakeCounter::waker();
+            let mut flush = pin!(tx.flush());
+            let poll = flush.as_mut().poll(&mut Context::from_waker(&sender_waker));
+            assert!(poll.is_pending());
+        }
+        assert_eq!(counter.wakes(), 2);
+        assert!(matches!(rx.poll_recv(&mut cx), Poll::Ready(Ok(3))));
+
+        // The receiver task wakes itself once the linger passed, although the sender went idle
+        rx.set_high_watermark(3, Some(Duration::from_millis(10)));
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        tx.try_send(4).unwrap();
+        assert_eq!(counter.wakes(), 2);
+        while counter.wakes() < 3 {
+            sleep(Duration::from_millis(1));
+        }
+        assert!(matches!(
+            rx.poll_recv_many(&mut cx, &mut buffer, 8),
+            Poll::Ready(Ok(1))
+        ));
+
+        // Once the linger passed, any value wakes the receiver task
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        while counter.wakes() < 4 {
+            sleep(Duration::from_millis(1));
+        }
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        tx.try_send(5).unwrap();
+        assert_eq!(counter.wakes(), 5);
+        assert!(matches!(
+            rx.poll_recv_many(&mut cx, &mut buffer, 8),
+            Poll::Ready(Ok(1))
+        ));
+
+        // The watermark is clamped to the capacity: a full channel wakes the receiver
+        rx.set_high_watermark(usize::MAX, None);
+        assert!(rx.poll_recv(&mut cx).is_pending());
+        let mut sent = 0;
+        while tx.try_send(6).is_ok() {
+            sent += 1;
+        }
+        assert_eq!(sent, rx.capacity());
+        assert_eq!(counter.wakes(), 6);
+        assert_eq!(buffer, [0, 1, 2, 4, 5]);
+    }
+
+    /// A receiver waiting below its high watermark gets the values of a sender that stops,
+    /// without flushing, once the linger passed
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_linger_idle_sender() {
+        use crate::spsc::timer::{ThreadTimer, Timer};
+        use futures::future::{Either, select};
+
+        let (mut tx, mut rx) = channel::<i32, 64>();
+        rx.set_high_watermark(16, Some(Duration::from_millis(50)));
+
+        let (stop_tx, stop_rx) = std::sync::mpsc::channel::<()>();
+        let producer = std::thread::spawn(move || {
+            sleep(Duration::from_millis(10));
+            tx.send_spin(1).unwrap();
+            tx.send_spin(2).unwrap();
+            // Idle below the watermark, until the receiver is done
+            stop_rx.recv().unwrap();
+        });
+
+        futures::executor::block_on(async {
+            let deadline = Instant::now() + Duration::from_secs(10);
+            let mut buffer = Vec::new();
+            while buffer.len() < 2 {
+                let received = rx.recv_many(&mut buffer, 64);
+                match select(received, ThreadTimer.sleep_until(deadline)).await {
+                    Either::Left((received, _)) => assert!(received.is_ok()),
+                    Either::Right(_) => panic!("the receiver waited past the linger"),
+                }
+            }
+            assert_eq!(buffer, [1, 2]);
+        });
+        stop_tx.send(()).unwrap();
+        producer.join().unwrap();
+    }
+
+    /// The sender task is woken once enough slots are free
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_low_watermark() {
+        use std::task::{Context, Poll};
+
+        let (counter, waker) = WakeCounter::waker();
+        let mut cx = Context::from_waker(&waker);
+        let (mut tx, mut rx) = channel::<i32, 8>();
+
+        tx.set_low_watermark(3);
+        while tx.try_send(0).is_ok() {}
+        assert!(tx.poll_ready(&mut cx).is_pending());
+        rx.try_recv().unwrap();
+        for _ in rx.drain(1) {}
+        assert_eq!(counter.wakes(), 0);
+        rx.try_recv().unwrap();
+        assert_eq!(counter.wakes(), 1);
+        assert!(matches!(tx.poll_ready(&mut cx), Poll::Ready(Ok(()))));
+
+        // Dropping the receiver wakes the sender below the watermark
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        tx.try_send(3).unwrap();
+        assert!(tx.poll_ready(&mut cx).is_pending());
+        drop(rx);
+        assert_eq!(counter.wakes(), 2);
+    }
+
+    /// Receiving futures yield once, waking their task, after handing out their budget
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_coop_budget() {
+        use futures::StreamExt;
+        use std::{
+            pin::pin,
+            task::{Context, Poll},
+        };
+
+        let (counter, waker) = WakeCounter::waker();
+        let mut cx = Context::from_waker(&waker);
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        for i in 0..8 {
+            tx.try_send(i).unwrap();
+        }
+
+        rx.set_coop_budget(Some(3));
+        let mut received = Vec::new();
+        for _ in 0..2 {
+            for _ in 0..3 {
+                match pin!(rx.recv()).poll(&mut cx) {
+                    Poll::Ready(Ok(v)) => received.push(v),
+                    poll => panic!("unexpected {poll:?}"),
+                }
+            }
+            assert!(pin!(rx.recv()).poll(&mut cx).is_pending());
+        }
+        assert_eq!(counter.wakes(), 2);
+        assert_eq!(received, [0, 1, 2, 3, 4, 5]);
+
+        // The stream shares the budget of the receiver
+        assert!(matches!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(6))));
+
+        rx.set_coop_budget(None);
+        assert!(matches!(rx.poll_next_unpin(&mut cx), Poll::Ready(Some(7))));
+        assert!(rx.poll_next_unpin(&mut cx).is_pending());
+        assert_eq!(counter.wakes(), 2);
+    }
+
+    /// A task whose receives are always ready still lets the other tasks run
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_coop_no_starvation() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        let busy = tokio::spawn(async move {
+            loop {
+                tx.try_send(0).unwrap();
+                rx.recv().await.unwrap();
+            }
+        });
+
+        // The runtime has a single thread: this only completes if the busy task yields
+        tokio::spawn(async {}).await.unwrap();
+        busy.abort();
+    }
+
+    /// With the `tokio-coop` feature, the budget of the tokio task applies as well
+    #[cfg(feature = "tokio-coop")]
+    #[tokio::test]
+    async fn test_tokio_coop() {
+        let (mut tx, mut rx) = channel::<i32, 2>();
+        rx.set_coop_budget(None);
+
+        let busy = tokio::spawn(async move {
+            loop {
+                tx.try_send(0).unwrap();
+                rx.recv().await.unwrap();
+            }
+        });
+
+        tokio::spawn(async {}).await.unwrap();
+        busy.abort();
+    }
+
+    /// A spinning future completes on its first poll if the other half makes progress in the
+    /// meantime, and yields once it gives up
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_spin_futures() {
+        use crate::spsc::wait::Spin;
+        use std::{
+            pin::pin,
+            task::{Context, Poll},
+        };
+
+        let (_, waker) = WakeCounter::waker();
+        let mut cx = Context::from_waker(&waker);
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        {
+            let mut recv = pin!(rx.recv().with_spin(Spin::Iterations(10)));
+            assert!(recv.as_mut().poll(&mut cx).is_pending());
+        }
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                sleep(Duration::from_millis(10));
+                tx.try_send(1).unwrap();
+            });
+            let mut recv = pin!(rx.recv().with_spin(Spin::For(Duration::from_secs(10))));
+            assert!(matches!(recv.as_mut().poll(&mut cx), Poll::Ready(Ok(1))));
+        });
+
+        while tx.try_send(0).is_ok() {}
+        {
+            let mut send = pin!(tx.send(2).with_spin(Spin::Iterations(10)));
+            assert!(send.as_mut().poll(&mut cx).is_pending());
+        }
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                sleep(Duration::from_millis(10));
+                rx.try_recv().unwrap();
+            });
+            let mut send = pin!(tx.send(3).with_spin(Spin::For(Duration::from_secs(10))));
+            assert!(matches!(send.as_mut().poll(&mut cx), Poll::Ready(Ok(()))));
+        });
+    }
+
+    /// The async timeouts give up once the deadline is reached, give the value back, and tell
+    /// the disconnection apart
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_async_timeout() {
+        use futures::executor::block_on;
+
+        let (mut tx, mut rx) = channel::<i32, 2>();
+
+        let start = Instant::now();
+        assert!(matches!(
+            block_on(rx.recv_timeout_async(Duration::from_millis(10))),
+            Err(RecvTimeoutError::Timeout)
+        ));
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        assert!(matches!(
+            block_on(tx.send_timeout_async(3, Duration::from_millis(10))),
+            Err(SendTimeoutError::Timeout(3))
+        ));
+
+        // Woken by the other half before the deadline
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                sleep(Duration::from_millis(10));
+                assert_eq!(rx.recv_blocking().unwrap(), 1);
+            });
+            block_on(tx.send_timeout_async(3, Duration::from_secs(10))).unwrap();
+        });
+        assert_eq!(block_on(rx.recv_timeout_async(Duration::ZERO)).unwrap(), 2);
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                sleep(Duration::from_millis(10));
+                tx.send_blocking(4).unwrap();
+            });
+            assert_eq!(
+                block_on(rx.recv_timeout_async(Duration::from_secs(10))).unwrap(),
+                3
+            );
+            assert_eq!(
+                block_on(rx.recv_timeout_async(Duration::from_secs(10))).unwrap(),
+                4
+            );
+        });
+
+        // Overflowing deadlines wait forever
+        tx.try_send(5).unwrap();
+        assert_eq!(block_on(rx.recv_timeout_async(Duration::MAX)).unwrap(), 5);
+
+        drop(tx);
+        assert!(matches!(
+            block_on(rx.recv_timeout_async(Duration::from_secs(10))),
+            Err(RecvTimeoutError::Disconnected)
+        ));
+
+        let (mut tx, rx) = channel::<i32, 2>();
+        drop(rx);
+        assert!(matches!(
+            block_on(tx.send_timeout_async(1, Duration::from_secs(10))),
+            Err(SendTimeoutError::Disconnected(1))
+        ));
+    }
+
+    /// With the `tokio-timer` feature, the deadline can be tracked by the tokio timer
+    #[cfg(feature = "tokio-timer")]
+    #[tokio::test]
+    async fn test_tokio_timer() {
+        use crate::spsc::timer::TokioTimer;
+
+        let (mut tx, mut rx) = channel::<i32, 2>();
+        assert!(matches!(
+            rx.recv_timeout_async(Duration::from_millis(10))
+                .with_timer(TokioTimer)
+                .await,
+            Err(RecvTimeoutError::Timeout)
+        ));
+
+        tx.try_send(0).unwrap();
+        tx.try_send(1).unwrap();
+        assert!(matches!(
+            tx.send_timeout_async(2, Duration::from_millis(10))
+                .with_timer(TokioTimer)
+                .await,
+            Err(SendTimeoutError::Timeout(2))
+        ));
+        assert_eq!(
+            rx.recv_timeout_async(Duration::from_millis(10))
+                .with_timer(TokioTimer)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_drain_all() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        assert!(rx.is_empty());
+    }
+
+    #[test]
+    fn test_drain_with_max() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        // Drain only 3 of 5
+        let items: Vec<_> = rx.drain(3).collect();
+        assert_eq!(items, vec![0, 1, 2]);
+        assert_eq!(rx.len(), 2);
+
+        // Drain remaining
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_drain_empty() {
+        let (_tx, mut rx) = channel::<i32, 8>();
+        let items: Vec<_> = rx.drain(100).collect();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn test_drain_partial_consume() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        // Consume only 2 items via early break
+        {
+            let mut drain = rx.drain(usize::MAX);
+            assert_eq!(drain.next(), Some(0));
+            assert_eq!(drain.next(), Some(1));
+            // drop drain here - should commit 2 items
+        }
+
+        // Remaining 3 items should still be there
+        assert_eq!(rx.len(), 3);
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_drain_remaining() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        // Note: remaining() is now the max allowed, not exact count
+        let mut drain = rx.drain(usize::MAX);
+        assert_eq!(drain.remaining(), usize::MAX);
+
+        drain.next();
+        assert_eq!(drain.remaining(), usize::MAX - 1);
+
+        drain.next();
+        drain.next();
+        assert_eq!(drain.remaining(), usize::MAX - 3);
+    }
+
+    #[test]
+    fn test_drain_after_sender_dropped() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        drop(tx);
+
+        assert!(rx.is_closed());
+
+        // Should still drain buffered items
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_drain_non_copy_types() {
+        let (mut tx, mut rx) = channel::<String, 4>();
+        tx.try_send("hello".into()).unwrap();
+        tx.try_send("world".into()).unwrap();
+
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec!["hello", "world"]);
+    }
+
+    #[test]
+    fn test_drain_multiple_rounds() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+
+        // Round 1
+        tx.try_send(1).unwrap();
+        tx.try_send(2).unwrap();
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![1, 2]);
+
+        // Round 2 - buffer slots should be reusable
+        tx.try_send(3).unwrap();
+        tx.try_send(4).unwrap();
+        tx.try_send(5).unwrap();
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_drain_max_zero() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        tx.try_send(1).unwrap();
+
+        // max=0 should yield nothing
+        let items: Vec<_> = rx.drain(0).collect();
+        assert!(items.is_empty());
+
+        // Item should still be there
+        assert_eq!(rx.len(), 1);
+    }
+
+    #[test]
+    fn test_drain_is_closed() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        tx.try_send(1).unwrap();
+
+        {
+            let drain = rx.drain(usize::MAX);
+            assert!(!drain.is_closed());
+        }
+
+        drop(tx);
+
+        {
+            let drain = rx.drain(usize::MAX);
+            assert!(drain.is_closed());
+        }
+    }
+
+    #[test]
+    fn test_fill() {
+        let (mut tx, mut rx) = channel::<i32, 8>();
+        {
+            let mut fill = tx.fill(5);
+            assert_eq!(fill.remaining(), 5);
+            for i in 0..5 {
+                fill.push(i).unwrap();
+            }
+            assert_eq!(fill.remaining(), 0);
+            assert_eq!(fill.push(5), Err(5));
+        }
+
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+    }
+
+    /// The values of a fill are published together, once it drops
+    #[test]
+    fn test_fill_published_on_drop() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+        let mut fill = tx.fill(usize::MAX);
+        fill.push(0).unwrap();
+        fill.push(1).unwrap();
+        assert!(rx.is_empty());
+        assert!(matches!(rx.try_recv(), Err(TryRecvError::Empty)));
+
+        drop(fill);
+        assert_eq!(rx.len(), 2);
+        assert_eq!(rx.drain(usize::MAX).collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_fill_full_channel() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+        tx.try_send(0).unwrap();
+
+        {
+            let mut fill = tx.fill(usize::MAX);
+            for i in 1..4 {
+                fill.push(i).unwrap();
+            }
+            // only 3 slots were free
+            assert_eq!(fill.push(4), Err(4));
+        }
+        assert!(matches!(tx.try_send(4), Err(TrySendErr::Full(4))));
+
+        // wrap around: the slots freed by the receiver are available to the next fill
+        assert_eq!(rx.drain(2).collect::<Vec<_>>(), vec![0, 1]);
+        {
+            let mut fill = tx.fill(usize::MAX);
+            fill.push(4).unwrap();
+            fill.push(5).unwrap();
+            assert_eq!(fill.push(6), Err(6));
+        }
+        assert_eq!(rx.drain(usize::MAX).collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_fill_max_zero() {
+        let (mut tx, rx) = channel::<i32, 8>();
+        assert_eq!(tx.fill(0).push(1), Err(1));
+        assert!(rx.is_empty());
+    }
+
+    /// Items pushed before a panic are published when the `Fill` unwinds
+    #[test]
+    fn test_fill_panic_safe() {
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, mut rx) = channel::<DropCounter, 8>();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut fill = tx.fill(usize::MAX);
+            fill.push(DropCounter(inner.clone())).unwrap();
+            fill.push(DropCounter(inner.clone())).unwrap();
+            panic!("producer failed mid-batch");
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(rx.len(), 2);
+        assert_eq!(inner.load(Ordering::SeqCst), 0);
+        drop(rx.try_recv().unwrap());
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+
+        // the other item is dropped with the channel
+        drop(tx);
+        drop(rx);
+        assert_eq!(inner.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_fill_is_closed() {
+        let (mut tx, rx) = channel::<i32, 8>();
+        assert!(!tx.fill(1).is_closed());
+        drop(rx);
+        assert!(tx.fill(1).is_closed());
+    }
+
+    /// Batches of pushes and drains preserve ordering between threads
+    #[test]
+    fn test_fill_threads() {
+        const COUNT: i32 = 10_000;
+        let (mut tx, mut rx) = channel::<i32, 64>();
+
+        let producer = std::thread::spawn(move || {
+            let mut next = 0;
+            while next < COUNT {
+                {
+                    let mut fill = tx.fill(16);
+                    while next < COUNT && fill.push(next).is_ok() {
+                        next += 1;
+                    }
+                }
+                std::thread::yield_now();
+            }
+        });
+
+        let mut expected = 0;
+        while expected < COUNT {
+            for v in rx.drain(usize::MAX) {
+                assert_eq!(v, expected);
+                expected += 1;
+            }
+            std::thread::yield_now();
+        }
+        producer.join().unwrap();
+    }
+
+    /// A reserved slot is published on commit only
+    #[test]
+    fn test_reserve() {
+        let (mut tx, mut rx) = channel::<[u64; 512], 2>();
+
+        // dropped without commit: nothing is published
+        {
+            let mut slot = tx.reserve().unwrap();
+            slot.as_uninit().write([1; 512]);
+        }
+        assert!(rx.is_empty());
+
+        let mut slot = tx.reserve().unwrap();
+        let snapshot = slot.as_uninit().as_mut_ptr();
+        unsafe {
+            for i in 0..512 {
+                (*snapshot)[i] = i as u64;
+            }
+            slot.commit();
+        }
+        tx.reserve().unwrap().write([7; 512]);
+
+        // the channel is full
+        assert!(tx.reserve().is_none());
+
+        let out = rx.try_recv().unwrap();
+        assert!(out.iter().enumerate().all(|(i, v)| *v == i as u64));
+        assert_eq!(rx.try_recv().unwrap(), [7; 512]);
+        assert!(rx.is_empty());
+    }
+
+    /// Committed values are owned by the channel, and dropped with it if unread
+    #[test]
+    fn test_reserve_drop() {
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, mut rx) = channel::<DropCounter, 4>();
+
+        tx.reserve().unwrap().write(DropCounter(inner.clone()));
+        tx.reserve().unwrap().write(DropCounter(inner.clone()));
+        drop(rx.try_recv().unwrap());
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+
+        drop(rx);
+        assert!(tx.reserve().is_none());
+        drop(tx);
+        assert_eq!(inner.load(Ordering::SeqCst), 2);
+    }
+
+    /// The slot is released, and the value dropped in place, when the guard drops
+    #[test]
+    fn test_recv_ref() {
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, mut rx) = channel::<(DropCounter, u32), 2>();
+
+        assert!(matches!(rx.recv_ref(), Err(TryRecvError::Empty)));
+
+        tx.try_send((DropCounter(inner.clone()), 1)).unwrap();
+        tx.try_send((DropCounter(inner.clone()), 2)).unwrap();
+        {
+            let mut guard = rx.recv_ref().unwrap();
+            assert_eq!(guard.1, 1);
+            guard.1 = 10;
+            assert_eq!(guard.1, 10);
+
+            // the slot is still owned by the receiver
+            assert!(matches!(
+                tx.try_send((DropCounter(inner.clone()), 3)),
+                Err(TrySendErr::Full(..))
+            ));
+        }
+        // one dropped by the guard, one given back by the failed send
+        assert_eq!(inner.load(Ordering::SeqCst), 2);
+
+        tx.try_send((DropCounter(inner.clone()), 3)).unwrap();
+        assert_eq!(rx.recv_ref().unwrap().1, 2);
+        assert_eq!(rx.try_recv().unwrap().1, 3);
+        assert_eq!(inner.load(Ordering::SeqCst), 4);
+
+        drop(tx);
+        assert!(matches!(rx.recv_ref(), Err(TryRecvError::Disconnected)));
+    }
+
+    /// A panicking destructor still releases the slot, and the value is not dropped twice
+    #[test]
+    fn test_recv_ref_panic_safe() {
+        #[derive(Debug)]
+        struct PanicOnDrop(Arc<AtomicUsize>);
+        impl Drop for PanicOnDrop {
+            fn drop(&mut self) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                panic!("destructor failed");
+            }
+        }
+
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(|_| {}));
+
+        let inner = Arc::new(AtomicUsize::new(0));
+        let (mut tx, mut rx) = channel::<PanicOnDrop, 2>();
+        tx.try_send(PanicOnDrop(inner.clone())).unwrap();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            drop(rx.recv_ref().unwrap());
+        }));
+
+        std::panic::set_hook(prev);
+
+        assert!(result.is_err());
+        assert!(rx.is_empty());
+        drop(tx);
+        drop(rx);
+        assert_eq!(inner.load(Ordering::SeqCst), 1);
+    }
+
+    /// Peeking doesn't consume the value
+    #[test]
+    fn test_peek() {
+        let (mut tx, mut rx) = channel::<String, 4>();
+        assert_eq!(rx.peek(), None);
+
+        tx.try_send("header".to_string()).unwrap();
+        assert_eq!(rx.peek().map(String::as_str), Some("header"));
+        assert_eq!(rx.peek().map(String::as_str), Some("header"));
+        assert_eq!(rx.len(), 1);
+
+        tx.try_send("body".to_string()).unwrap();
+        assert_eq!(rx.try_recv().unwrap(), "header");
+        assert_eq!(rx.peek().map(String::as_str), Some("body"));
+
+        drop(tx);
+        assert_eq!(rx.peek().map(String::as_str), Some("body"));
+        assert_eq!(rx.try_recv().unwrap(), "body");
+        assert_eq!(rx.peek(), None);
+    }
+
+    /// Iterating over the buffered values doesn't consume them, across the wrap-around
+    #[test]
+    fn test_iter_buffered() {
+        let (mut tx, mut rx) = channel::<i32, 4>();
+        assert_eq!(rx.iter_buffered().count(), 0);
+
+        for i in 0..4 {
+            tx.try_send(i).unwrap();
+        }
+        assert_eq!(
+            rx.iter_buffered().copied().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+
+        rx.try_recv().unwrap();
+        rx.try_recv().unwrap();
+        tx.try_send(4).unwrap();
+        assert_eq!(
+            rx.iter_buffered().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert_eq!(rx.len(), 3);
+
+        let items: Vec<_> = rx.drain(usize::MAX).collect();
+        assert_eq!(items, vec![2, 3, 4]);
+        assert_eq!(rx.iter_buffered().count(), 0);
+    }
+}
+
+/// Model-checked tests: run with `RUSTFLAGS="--cfg loom" cargo test --release --lib loom`
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::thread;
+
+    use crate::{
+        spsc::{TryRecvError, TrySendErr},
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    use super::*;
+
+    struct DropCounter(Arc<AtomicUsize>);
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// Explores the interleavings with up to 3 preemptions, unless `LOOM_MAX_PREEMPTIONS` is set:
+    /// an unbounded exploration of the async tests doesn't complete in a reasonable time
+    fn model(f: impl Fn() + Send + Sync + 'static) {
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound.get_or_insert(3);
+        builder.check(f);
+    }
+
+    /// Values are received in order, also once the ring wraps around
+    #[test]
+    fn loom_send_recv() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..3 {
+                    let mut value = i;
+                    while let Err(TrySendErr::Full(v)) = tx.try_send(value) {
+                        value = v;
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            for i in 0..3 {
+                loop {
+                    match rx.try_recv() {
+                        Ok(v) => {
+                            assert_eq!(v, i);
+                            break;
+                        }
+                        Err(TryRecvError::Empty) => thread::yield_now(),
+                        Err(TryRecvError::Disconnected) => panic!("value lost"),
+                    }
+                }
+            }
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// A value sent right before the sender drops is received before the disconnection
+    #[test]
+    fn loom_disconnect() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                tx.try_send(1).unwrap();
+                drop(tx);
+            });
+
+            let mut received = Vec::new();
+            loop {
+                match rx.try_recv() {
+                    Ok(v) => received.push(v),
+                    Err(TryRecvError::Empty) => thread::yield_now(),
+                    Err(TryRecvError::Disconnected) => break,
+                }
+            }
+            assert_eq!(received, vec![1]);
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// Draining observes the values in order, and hands the slots back to the producer
+    #[test]
+    fn loom_drain() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..3 {
+                    let mut value = i;
+                    while let Err(TrySendErr::Full(v)) = tx.try_send(value) {
+                        value = v;
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            let mut received = Vec::new();
+            while received.len() < 3 {
+                let before = received.len();
+                received.extend(rx.drain(usize::MAX));
+                if received.len() == before {
+                    thread::yield_now();
+                }
+            }
+            assert_eq!(received, vec![0, 1, 2]);
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// A fill publishes its values from the last to the first: the receiver sees the batch
+    /// at once, in order, and the slots it frees are filled by the next batch
+    #[test]
+    fn loom_fill() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                let mut next = 0;
+                while next < 3 {
+                    let mut fill = tx.fill(usize::MAX);
+                    while next < 3 && fill.push(next).is_ok() {
+                        next += 1;
+                    }
+                    drop(fill);
+                    thread::yield_now();
+                }
+            });
+
+            let mut received = Vec::new();
+            while received.len() < 3 {
+                match rx.try_recv() {
+                    Ok(v) => received.push(v),
+                    Err(TryRecvError::Empty) => thread::yield_now(),
+                    Err(TryRecvError::Disconnected) => panic!("value lost"),
+                }
+            }
+            assert_eq!(received, vec![0, 1, 2]);
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// Values left in the channel are dropped exactly once, whichever half drops last
+    #[test]
+    fn loom_drop() {
+        model(|| {
+            let drops = Arc::new(AtomicUsize::new(0));
+            let (mut tx, rx) = channel::<DropCounter, 2>();
+
+            let producer = {
+                let drops = drops.clone();
+                thread::spawn(move || {
+                    let _ = tx.try_send(DropCounter(drops));
+                })
+            };
+            drop(rx);
+
+            producer.join().unwrap();
+            assert_eq!(drops.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    /// Parked halves are always unparked: a missed unpark is reported as a deadlock
+    #[test]
+    fn loom_blocking() {
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..3 {
+                    tx.send_blocking(i).unwrap();
+                }
+            });
+
+            for i in 0..3 {
+                assert_eq!(rx.recv_blocking().unwrap(), i);
+            }
+            assert!(matches!(
+                rx.recv_blocking(),
+                Err(TryRecvError::Disconnected)
+            ));
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// Pending futures are always woken: a missed wakeup is reported as a deadlock
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_async() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..2 {
+                    block_on(tx.send(i)).unwrap();
+                }
+            });
+
+            for i in 0..2 {
+                assert_eq!(block_on(rx.recv()).unwrap(), i);
+            }
+            assert!(matches!(
+                block_on(rx.recv()),
+                Err(TryRecvError::Disconnected)
+            ));
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// Values sent right before the disconnection are part of the last batch
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_recv_many() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..3 {
+                    block_on(tx.send(i)).unwrap();
+                }
+            });
+
+            let mut received = Vec::new();
+            while block_on(rx.recv_many(&mut received, 2)).is_ok() {}
+            assert_eq!(received, [0, 1, 2]);
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// The flushing sender is woken once the receiver consumed the last value
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_flush() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let consumer = thread::spawn(move || {
+                for i in 0..2 {
+                    assert_eq!(block_on(rx.recv()).unwrap(), i);
+                }
+            });
+
+            for i in 0..2 {
+                block_on(tx.send(i)).unwrap();
+            }
+            block_on(tx.flush()).unwrap();
+
+            consumer.join().unwrap();
+        });
+    }
+
+    /// A sync sender wakes an async receiver
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_sync_sender_async_receiver() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let producer = thread::spawn(move || {
+                for i in 0..2 {
+                    tx.send_spin(i).unwrap();
+                }
+            });
+
+            for i in 0..2 {
+                assert_eq!(block_on(rx.recv()).unwrap(), i);
+            }
+
+            producer.join().unwrap();
+        });
+    }
+
+    /// A sync receiver wakes an async sender
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_async_sender_sync_receiver() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+
+            let consumer = thread::spawn(move || {
+                for i in 0..2 {
+                    assert_eq!(rx.recv_spin().unwrap(), i);
+                }
+            });
+
+            for i in 0..2 {
+                block_on(tx.send(i)).unwrap();
+            }
+
+            consumer.join().unwrap();
+        });
+    }
+
+    /// Values sent below the high watermark are received once the sender flushes
+    #[cfg(feature = "async")]
+    #[test]
+    fn loom_high_watermark() {
+        use loom::future::block_on;
+
+        model(|| {
+            let (mut tx, mut rx) = channel::<usize, 2>();
+            rx.set_high_watermark(2, None);
+
+            let consumer = thread::spawn(move || {
+                let mut received = Vec::new();
+                while block_on(rx.recv_many(&mut received, 2)).is_ok() {}
+                received
+            });
+
+            block_on(tx.send(0)).unwrap();
+            block_on(tx.flush()).unwrap();
+            block_on(tx.send(1)).unwrap();
+            drop(tx);
+
+            assert_eq!(consumer.join().unwrap(), [0, 1]);
+        });
     }
 }
@@ -1,26 +1,43 @@
 use super::Channel;
 use crate::ring::Storable;
-use crate::spsc::TrySendErr;
+use crate::spsc::SendError;
+#[cfg(feature = "async")]
+use crate::spsc::SpscAsyncSender;
+use crate::spsc::SpscSender;
+use crate::spsc::{
+    SendTimeoutError, TrySendErr,
+    wait::{Wait, WaitStrategy},
+};
+use crate::sync::{Arc, atomic::Ordering, hint};
+#[cfg(feature = "async")]
+use std::task::Context;
+use std::task::Poll;
 use std::{
     cell::Cell,
-    sync::{Arc, atomic::Ordering},
+    marker::PhantomData,
+    mem::MaybeUninit,
+    time::{Duration, Instant},
 };
 
 #[cfg(feature = "async")]
-pub use r#async::SendFuture;
+pub use r#async::{
+    ClosedFuture, FlushFuture, Permit, ReserveFuture, SendFuture, SendOwned, SendTimeout,
+};
 use crossbeam_utils::CachePadded;
 
 pub struct Sender<T, const N: usize> {
     pub(super) inner: CachePadded<Arc<Channel<T, N>>>,
     /// Local tail cursor - only modified by this sender.
-    tail: Cell<usize>,
+    tail: usize,
+    _not_sync: PhantomData<Cell<()>>, //marker type to keep the sender on one thread at a time
 }
 
 impl<T, const N: usize> Sender<T, N> {
     pub(super) fn new(inner: Arc<Channel<T, N>>) -> Self {
         Self {
             inner: CachePadded::new(inner),
-            tail: Cell::new(0),
+            tail: 0,
+            _not_sync: PhantomData,
         }
     }
 
@@ -30,12 +47,12 @@ impl<T, const N: usize> Sender<T, N> {
     /// - Check slot stamp: if stamp == tail, slot is ready for writing
     /// - Write value, then set stamp = tail + 1 (signals "data ready")
     /// - Advance local tail cursor
-    pub fn try_send(&self, value: T) -> Result<(), TrySendErr<T>> {
+    pub fn try_send(&mut self, value: T) -> Result<(), TrySendErr<T>> {
         if self.is_closed() {
             return Err(TrySendErr::Disconnected(value));
         }
 
-        let tail = self.tail.get();
+        let tail = self.tail;
         let index = self.inner.buffer.index(tail);
         let slot = self.inner.buffer.get(index);
 
@@ -46,13 +63,17 @@ impl<T, const N: usize> Sender<T, N> {
             // Slot is ready for writing
             unsafe { slot.write(value) };
 
-            let new_tail = tail.wrapping_add(1);
+            let new_tail = self.inner.buffer.next(tail);
 
             // Release: make the write visible before signaling "data ready"
             slot.store_stamp(new_tail);
 
-            // Advance local tail (Relaxed: we're the only writer)
-            self.tail.set(new_tail);
+            // Advance local tail (plain field: `&mut self` makes us the only writer)
+            self.tail = new_tail;
+
+            // Wake the receiver if it waits for a value (cheap when it doesn't)
+            self.inner
+                .notify_receiver_at(|high| self.holds_at_least(high));
 
             Ok(())
         } else {
@@ -69,14 +90,108 @@ impl<T, const N: usize> Sender<T, N> {
     ///
     /// This favors minimal latency over fairness, and avoids `thread::yield_now`,
     /// which may enter the scheduler and potentially deschedule the thread.
-    pub fn send_spin(&self, mut value: T) -> Result<(), TrySendErr<T>> {
+    pub fn send_spin(&mut self, mut value: T) -> Result<(), TrySendErr<T>> {
+        loop {
+            match self.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendErr::Disconnected(v)) => return Err(TrySendErr::Disconnected(v)),
+                Err(TrySendErr::Full(v)) => {
+                    value = v;
+                    hint::spin_loop();
+                }
+            }
+        }
+    }
+
+    /// Producer pushes a new value into the buffer, letting `strategy` decide how to idle
+    /// while the channel is full.
+    ///
+    /// When the strategy asks to [`Park`](Wait::Park), the thread is unparked by the receiver as
+    /// soon as it frees a slot (see [`send_blocking`](Self::send_blocking)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::{vyukov::channel, wait::Backoff};
+    ///
+    /// let (mut tx, mut rx) = channel::<i32, 4>();
+    /// tx.send_with(1, &mut Backoff::default()).unwrap();
+    /// assert_eq!(rx.recv_with(&mut Backoff::default()).unwrap(), 1);
+    /// ```
+    pub fn send_with<W: WaitStrategy>(
+        &mut self,
+        mut value: T,
+        strategy: &mut W,
+    ) -> Result<(), TrySendErr<T>> {
+        strategy.reset();
         loop {
             match self.try_send(value) {
                 Ok(()) => return Ok(()),
                 Err(TrySendErr::Disconnected(v)) => return Err(TrySendErr::Disconnected(v)),
                 Err(TrySendErr::Full(v)) => {
                     value = v;
-                    std::hint::spin_loop();
+                    if strategy.wait() == Wait::Park {
+                        self.inner
+                            .park_sender(None, || self.is_closed() || self.has_space());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Producer pushes a new value into the buffer, parking the thread while the channel is full.
+    ///
+    /// The thread is unparked by the receiver as soon as it frees a slot, whichever the
+    /// operation, or when the receiver drops.
+    ///
+    /// This trades latency for CPU time: prefer [`send_spin`](Self::send_spin) when the
+    /// receiver is expected to keep up.
+    pub fn send_blocking(&mut self, mut value: T) -> Result<(), TrySendErr<T>> {
+        loop {
+            match self.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendErr::Disconnected(v)) => return Err(TrySendErr::Disconnected(v)),
+                Err(TrySendErr::Full(v)) => {
+                    value = v;
+                    self.inner
+                        .park_sender(None, || self.is_closed() || self.has_space());
+                }
+            }
+        }
+    }
+
+    /// Like [`send_blocking`](Self::send_blocking), but gives up once `timeout` has elapsed.
+    ///
+    /// On timeout, the value is given back through [`SendTimeoutError::Timeout`].
+    pub fn send_timeout(&mut self, value: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.send_deadline(value, deadline),
+            // Too far in the future to be represented: wait forever
+            None => self
+                .send_blocking(value)
+                .map_err(|e| SendTimeoutError::Disconnected(e.into_inner())),
+        }
+    }
+
+    /// Like [`send_blocking`](Self::send_blocking), but gives up once `deadline` is reached.
+    ///
+    /// On timeout, the value is given back through [`SendTimeoutError::Timeout`].
+    pub fn send_deadline(
+        &mut self,
+        mut value: T,
+        deadline: Instant,
+    ) -> Result<(), SendTimeoutError<T>> {
+        loop {
+            match self.try_send(value) {
+                Ok(()) => return Ok(()),
+                Err(TrySendErr::Disconnected(v)) => return Err(SendTimeoutError::Disconnected(v)),
+                Err(TrySendErr::Full(v)) => {
+                    if Instant::now() >= deadline {
+                        return Err(SendTimeoutError::Timeout(v));
+                    }
+                    value = v;
+                    self.inner
+                        .park_sender(Some(deadline), || self.is_closed() || self.has_space());
                 }
             }
         }
@@ -94,48 +209,661 @@ impl<T, const N: usize> Sender<T, N> {
     ///
     /// **Not cancel-safe.** Dropping this future before completion loses the value.
     #[cfg(feature = "async")]
-    pub fn send(&self, value: T) -> SendFuture<'_, T, N> {
+    pub fn send(&mut self, value: T) -> SendFuture<'_, T, N> {
         SendFuture::new(self, value)
     }
 
-    /// Returns the channel capacity.
-    pub const fn capacity(&self) -> usize {
+    /// Like [`send()`](Self::send), but gives up once `timeout` has elapsed.
+    ///
+    /// On timeout, the value is given back through [`SendTimeoutError::Timeout`]. The deadline
+    /// is tracked by a [`ThreadTimer`](crate::spsc::timer::ThreadTimer), which works on any
+    /// executor; another [`Timer`](crate::spsc::timer::Timer) can be plugged in with
+    /// [`SendTimeout::with_timer()`].
+    ///
+    /// # Cancel Safety
+    ///
+    /// **Not cancel-safe.** Dropping this future before completion loses the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use veloce::spsc::{SendTimeoutError, vyukov::channel};
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, rx) = channel::<u32, 2>();
+    /// tx.send_timeout_async(1, Duration::from_millis(10)).await.unwrap();
+    /// tx.send_timeout_async(2, Duration::from_millis(10)).await.unwrap();
+    ///
+    /// let timeout = tx.send_timeout_async(3, Duration::from_millis(10)).await;
+    /// assert!(matches!(timeout, Err(SendTimeoutError::Timeout(3))));
+    ///
+    /// drop(rx);
+    /// let disconnected = tx.send_timeout_async(4, Duration::from_millis(10)).await;
+    /// assert!(matches!(disconnected, Err(SendTimeoutError::Disconnected(4))));
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn send_timeout_async(&mut self, value: T, timeout: Duration) -> SendTimeout<'_, T, N> {
+        SendTimeout::new(self, value, timeout)
+    }
+
+    /// Like [`send()`](Self::send), but the future owns the sender and hands it back along
+    /// with the result.
+    ///
+    /// [`SendOwned`] doesn't borrow anything, so it is `'static` (if `T` is): it can be boxed,
+    /// spawned, or stored in a `FuturesUnordered` that outlives the sender borrow.
+    ///
+    /// # Cancel Safety
+    ///
+    /// **Not cancel-safe.** Dropping this future before completion loses the value, and
+    /// drops the sender, which disconnects the channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (tx, mut rx) = channel::<u32, 4>();
+    /// let send: futures::future::BoxFuture<'static, _> = Box::pin(tx.send_owned(1));
+    ///
+    /// let (tx, result) = send.await;
+    /// assert!(result.is_ok());
+    /// assert_eq!(rx.try_recv().unwrap(), 1);
+    /// # drop(tx);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn send_owned(self, value: T) -> SendOwned<T, N> {
+        SendOwned::new(self, value)
+    }
+
+    /// Waits for a free slot, and reserves it for a later [`Permit::send`].
+    ///
+    /// Unlike [`send()`](Self::send), no value is handed over until the slot is free: in a
+    /// `select!` loop, a value is never lost with the cancelled future. Being the only
+    /// producer, the permit holds the slot without taking it away from anyone, so sending
+    /// through it can't fail.
+    ///
+    /// Resolves to [`SendError`] if the receiver has been dropped.
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe. Dropping it, or the [`Permit`], gives the slot back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 4>();
+    ///
+    /// let permit = tx.reserve_async().await.unwrap();
+    /// permit.send(1);
+    /// assert_eq!(rx.try_recv().unwrap(), 1);
+    ///
+    /// drop(rx);
+    /// assert!(tx.reserve_async().await.is_err());
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn reserve_async(&mut self) -> ReserveFuture<'_, T, N> {
+        ReserveFuture::new(self)
+    }
+
+    /// Waits for the receiver to drop.
+    ///
+    /// The sender is woken by the receiver's drop. Resolves right away if it already dropped.
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe.
+    #[cfg(feature = "async")]
+    pub fn closed(&mut self) -> ClosedFuture<'_, T, N> {
+        ClosedFuture::new(self)
+    }
+
+    /// Parks the thread until the receiver drops.
+    pub fn closed_blocking(&mut self) {
+        while !self.is_closed() {
+            self.inner.park_sender(None, || self.is_closed());
+        }
+    }
+
+    /// Busy-spins until the receiver drops.
+    pub fn closed_spin(&mut self) {
+        while !self.is_closed() {
+            hint::spin_loop();
+        }
+    }
+
+    /// Waits until every value sent so far has been consumed by the receiver.
+    ///
+    /// Resolves to [`SendError`] if the receiver drops before consuming them all. The sender
+    /// is woken by the receiver as it frees slots, or when it drops. A receiver task waiting
+    /// below its [high watermark](crate::spsc::vyukov::Receiver::set_high_watermark) is woken to consume the last values.
+    ///
+    /// Unlike [`Sink::poll_flush`](futures::Sink::poll_flush), which has nothing to wait for
+    /// since sent values are visible right away, this waits for them to be received: before
+    /// exiting, a producer knows that everything it wrote has been processed.
+    ///
+    /// # Cancel Safety
+    ///
+    /// This future is cancel-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 4>();
+    /// tx.send(1).await.unwrap();
+    ///
+    /// let consumer = async move {
+    ///     assert_eq!(rx.recv().await.unwrap(), 1);
+    ///     rx
+    /// };
+    /// let (flushed, _rx) = futures::join!(tx.flush(), consumer);
+    /// assert!(flushed.is_ok());
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn flush(&mut self) -> FlushFuture<'_, T, N> {
+        FlushFuture::new(self)
+    }
+
+    /// Parks the thread until every value sent so far has been consumed by the receiver.
+    ///
+    /// The thread is unparked by the receiver as it frees slots, or when it drops.
+    /// Fails with [`SendError`] if the receiver drops before consuming them all.
+    pub fn flush_blocking(&mut self) -> Result<(), SendError> {
+        // A receiver task waiting below its watermark must consume the last values
+        self.inner.notify_receiver();
+        loop {
+            if let Poll::Ready(result) = self.flushed() {
+                return result;
+            }
+            self.inner.park_sender(None, || self.flushed().is_ready());
+        }
+    }
+
+    /// Busy-spins until every value sent so far has been consumed by the receiver.
+    ///
+    /// Fails with [`SendError`] if the receiver drops before consuming them all.
+    pub fn flush_spin(&mut self) -> Result<(), SendError> {
+        // A receiver task waiting below its watermark must consume the last values
+        self.inner.notify_receiver();
+        loop {
+            if let Poll::Ready(result) = self.flushed() {
+                return result;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    /// Coalesces the wakes of the sending task: once it waits for a free slot, it is only
+    /// woken when `low` slots are free, instead of on every slot freed by the receiver.
+    ///
+    /// `low` is clamped to `1..=capacity`, and defaults to `1`. Under a steady load, each wake
+    /// then lets the task send a batch. Threads parked in [`send_blocking`](Self::send_blocking)
+    /// are unparked on every freed slot regardless.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let (mut tx, mut rx) = channel::<u32, 8>();
+    /// tx.set_low_watermark(4);
+    ///
+    /// let producer = async move {
+    ///     // Woken once 4 slots are free, rather than once per value
+    ///     for i in 0..32 {
+    ///         tx.send(i).await.unwrap();
+    ///     }
+    /// };
+    /// let consumer = async move {
+    ///     for i in 0..32 {
+    ///         assert_eq!(rx.recv().await.unwrap(), i);
+    ///     }
+    /// };
+    /// futures::join!(producer, consumer);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn set_low_watermark(&mut self, low: usize) {
+        self.inner
+            .set_sender_watermark(low.clamp(1, self.capacity()));
+    }
+
+    /// Polls for a free slot, registering the sender waker while the channel is full.
+    ///
+    /// Once it returns `Poll::Ready(Ok(()))`, the next [`start_send()`](Self::start_send) can't
+    /// fail with [`TrySendErr::Full`]: only this sender fills the channel. This is what
+    /// [`send()`](Self::send) does while the channel is full, exposed for hand-written futures
+    /// and state machines that can't hold a [`SendFuture`]:
+    /// - `Poll::Ready(Ok(()))`: a slot is free
+    /// - `Poll::Pending`: `cx` is woken once the receiver frees a slot or drops
+    /// - `Poll::Ready(Err(SendError))`: the receiver dropped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::task::{Context, Poll, Waker};
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<i32, 2>();
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// while let Poll::Ready(Ok(())) = tx.poll_ready(&mut cx) {
+    ///     tx.start_send(1).unwrap();
+    /// }
+    /// assert_eq!(rx.len(), 2);
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        if self.is_closed() {
+            return Poll::Ready(Err(SendError));
+        }
+        if self.has_space() {
+            return Poll::Ready(Ok(()));
+        }
+
+        // we store the waker for future polls
+        self.inner.register_sender_waker(cx.waker());
+
+        // We give a second shot, the receiver may have freed a slot or dropped in the meanwhile
+        if self.is_closed() {
+            return Poll::Ready(Err(SendError));
+        }
+        if self.has_space() {
+            return Poll::Ready(Ok(()));
+        }
+
+        Poll::Pending
+    }
+
+    /// Sends `value` once [`poll_ready()`](Self::poll_ready) returned `Poll::Ready(Ok(()))`.
+    ///
+    /// Same as [`try_send()`](Self::try_send), which already notifies a receiver waiting in
+    /// [`recv()`](crate::spsc::vyukov::Receiver::recv) or
+    /// [`poll_recv()`](crate::spsc::vyukov::Receiver::poll_recv): named after
+    /// [`Sink::start_send`](futures::Sink::start_send) for hand-written futures.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn start_send(&mut self, value: T) -> Result<(), TrySendErr<T>> {
+        self.try_send(value)
+    }
+
+    /// Pushes up to `max` items into the channel as a batch.
+    ///
+    /// Returns a [`Fill`] guard that accepts items through [`Fill::push`]. The `&mut self`
+    /// borrow prevents concurrent access to the sender until the `Fill` is dropped.
+    ///
+    /// # Performance
+    ///
+    /// The values are written as they are pushed, but their stamps are only stored when the
+    /// `Fill` drops, from the last slot to the first: the receiver, which checks the first
+    /// stamp before the others, sees the whole batch at once and is notified once. In the
+    /// meantime, it doesn't pull the cache lines of the slots being filled.
+    ///
+    /// Unlike lamport's single `tail` store, publishing still stores one stamp per value:
+    /// the gain over a [`try_send()`](Self::try_send) loop is smaller.
+    ///
+    /// The trade-off: the receiver won't see the pushed items until the `Fill` drops.
+    ///
+    /// # Behavior
+    ///
+    /// - Accepts items as long as slots are free (lazy evaluation)
+    /// - Does not signal disconnection — check [`is_closed()`](Self::is_closed) before
+    /// - Panic-safe: pushed items are published even if the producer panics mid-batch
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<i32, 4>();
+    /// {
+    ///     let mut fill = tx.fill(8);
+    ///     for i in 0..4 {
+    ///         fill.push(i).unwrap();
+    ///     }
+    ///     // the channel is full
+    ///     assert_eq!(fill.push(4), Err(4));
+    /// } // published here
+    ///
+    /// assert_eq!(rx.drain(usize::MAX).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, max: usize) -> Fill<'_, T, N> {
+        Fill {
+            original_tail: self.tail,
+            tail: self.tail,
+            tx: self,
+            remaining: max,
+        }
+    }
+
+    /// Reserves the next free slot, to build a value directly in the channel's memory.
+    ///
+    /// Returns `None` if the channel is full or the receiver has been dropped.
+    /// The value becomes visible to the receiver on [`WriteSlot::commit`]; dropping the
+    /// [`WriteSlot`] without committing publishes nothing. The `&mut self` borrow prevents
+    /// concurrent access to the sender until the `WriteSlot` is dropped.
+    ///
+    /// This avoids building large messages on the stack and copying them into the slot.
+    /// For async code, [`reserve_async()`](Self::reserve_async) waits for the free slot instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::channel;
+    ///
+    /// let (mut tx, mut rx) = channel::<[u8; 4096], 4>();
+    ///
+    /// let mut slot = tx.reserve().unwrap();
+    /// let snapshot = slot.as_uninit().as_mut_ptr();
+    /// unsafe {
+    ///     // build the message in place, byte by byte
+    ///     for i in 0..4096 {
+    ///         (*snapshot)[i] = i as u8;
+    ///     }
+    ///     slot.commit();
+    /// }
+    ///
+    /// assert_eq!(rx.try_recv().unwrap()[4095], 255);
+    /// ```
+    pub fn reserve(&mut self) -> Option<WriteSlot<'_, T, N>> {
+        if self.is_closed() || !self.has_space() {
+            return None;
+        }
+
+        let tail = self.tail;
+        Some(WriteSlot { tx: self, tail })
+    }
+
+    /// Capacity of a channel sized at compile time, usable in `const` contexts.
+    ///
+    /// Channels sized at runtime ([`DYNAMIC`](crate::DYNAMIC)) don't know theirs until built, so
+    /// using it on them fails to compile: see [`capacity()`](Self::capacity) instead.
+    ///
+    /// ```
+    /// use veloce::spsc::vyukov::Sender;
+    ///
+    /// const CAPACITY: usize = Sender::<u64, 8>::CAPACITY;
+    /// assert_eq!(CAPACITY, 8);
+    /// ```
+    ///
+    /// ```compile_fail,E0080
+    /// use veloce::{DYNAMIC, spsc::vyukov::Sender};
+    ///
+    /// let _ = Sender::<u64, DYNAMIC>::CAPACITY;
+    /// ```
+    pub const CAPACITY: usize = {
+        assert!(
+            N != crate::ring::DYNAMIC,
+            "the capacity is only known at runtime"
+        );
         N
+    };
+
+    /// Returns the channel capacity.
+    ///
+    /// For channels sized at compile time, it is [`Self::CAPACITY`].
+    pub fn capacity(&self) -> usize {
+        self.inner.buffer.capacity()
     }
 
     /// Returns true if the receiver has been dropped.
     pub fn is_closed(&self) -> bool {
         self.inner.is_closed()
     }
+
+    /// Returns true if the slot at the current tail is ready for writing.
+    fn has_space(&self) -> bool {
+        let tail = self.tail;
+        let index = self.inner.buffer.index(tail);
+        self.inner.buffer.get(index).load_stamp() == tail
+    }
+
+    /// Returns true if at least `count` values, `1..=capacity`, are waiting to be received.
+    ///
+    /// Values are consumed in order, so it is the case if the receiver didn't consume the
+    /// `count`-th last value sent. Once read, the slot of the sequence `tail - count` is stamped
+    /// `tail - count + capacity`: before the first lap, it is the initial stamp of the slot.
+    fn holds_at_least(&self, count: usize) -> bool {
+        let seq = self
+            .inner
+            .buffer
+            .advance(self.tail, self.capacity() - count);
+        let index = self.inner.buffer.index(seq);
+        self.inner.buffer.get(index).load_stamp() != seq
+    }
+
+    /// Returns true if the receiver consumed the last value sent, hence all of them.
+    fn is_flushed(&self) -> bool {
+        !self.holds_at_least(1)
+    }
+
+    /// Returns `Ready` once the receiver consumed every value, or once it dropped before doing so.
+    fn flushed(&self) -> Poll<Result<(), SendError>> {
+        if self.is_flushed() {
+            return Poll::Ready(Ok(()));
+        }
+        if self.is_closed() {
+            // The receiver may have consumed the last values right before dropping
+            return Poll::Ready(if self.is_flushed() {
+                Ok(())
+            } else {
+                Err(SendError)
+            });
+        }
+        Poll::Pending
+    }
+
+    /// Disconnects the channel, waking the receiver to let it acknowledge it.
+    fn close(&self) {
+        self.inner.closed.store(true, Ordering::Release);
+
+        // wake the other half to let it acknowledge disconnection
+        self.inner.notify_receiver();
+    }
 }
 
 impl<T, const N: usize> Drop for Sender<T, N> {
     fn drop(&mut self) {
-        self.inner.closed.store(true, Ordering::Release);
+        self.close();
+    }
+}
 
-        // wake the other half to let it acknowledge disconnection
-        #[cfg(feature = "async")]
-        self.inner.wake_receiver();
+impl<T, const N: usize> SpscSender for Sender<T, N> {
+    type Item = T;
+
+    fn try_send(&mut self, value: T) -> Result<(), TrySendErr<T>> {
+        Sender::try_send(self, value)
+    }
+
+    fn send_spin(&mut self, value: T) -> Result<(), TrySendErr<T>> {
+        Sender::send_spin(self, value)
+    }
+
+    fn capacity(&self) -> usize {
+        Sender::capacity(self)
+    }
+
+    fn is_closed(&self) -> bool {
+        Sender::is_closed(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T, const N: usize> SpscAsyncSender for Sender<T, N> {
+    type SendFuture<'a>
+        = SendFuture<'a, T, N>
+    where
+        Self: 'a;
+
+    fn send(&mut self, value: T) -> SendFuture<'_, T, N> {
+        Sender::send(self, value)
+    }
+}
+
+/// Free slot reserved by [`Sender::reserve()`].
+///
+/// Dropping it without calling [`commit`](Self::commit) publishes nothing: a value written
+/// through [`as_uninit`](Self::as_uninit) is then leaked, not dropped.
+pub struct WriteSlot<'a, T, const N: usize> {
+    tx: &'a mut Sender<T, N>,
+    /// Sequence of the reserved slot.
+    tail: usize,
+}
+
+impl<T, const N: usize> WriteSlot<'_, T, N> {
+    /// Returns the memory of the reserved slot.
+    #[inline]
+    pub fn as_uninit(&mut self) -> &mut MaybeUninit<T> {
+        let index = self.tx.inner.buffer.index(self.tail);
+
+        // Safety: the stamp says the slot is free, and only the producer can access a free slot
+        unsafe { &mut *self.tx.inner.buffer.get(index).as_ptr() }
+    }
+
+    /// Publishes the value built in the slot to the receiver.
+    ///
+    /// # Safety
+    ///
+    /// The slot must have been initialized through [`as_uninit`](Self::as_uninit).
+    #[inline]
+    pub unsafe fn commit(self) {
+        let index = self.tx.inner.buffer.index(self.tail);
+        let new_tail = self.tx.inner.buffer.next(self.tail);
+
+        // Release: make the write visible before signaling "data ready"
+        self.tx.inner.buffer.get(index).store_stamp(new_tail);
+
+        // Advance local tail (plain field: `&mut self` makes us the only writer)
+        self.tx.tail = new_tail;
+
+        self.tx
+            .inner
+            .notify_receiver_at(|high| self.tx.holds_at_least(high));
+    }
+
+    /// Moves `value` into the slot and publishes it.
+    #[inline]
+    pub fn write(mut self, value: T) {
+        self.as_uninit().write(value);
+        unsafe { self.commit() };
+    }
+}
+
+/// Batching guard created by [`Sender::fill()`].
+///
+/// Writes items to the slots following `original_tail` without publishing them.
+/// On drop, publishes all pushed items at once, storing their stamps from the last slot to the
+/// first, and notifies the receiver once.
+pub struct Fill<'a, T, const N: usize> {
+    tx: &'a mut Sender<T, N>,
+    /// Tail at construction: first slot of the batch.
+    original_tail: usize,
+    /// Next slot to write, published to the sender on drop.
+    tail: usize,
+    /// Maximum items remaining to push.
+    remaining: usize,
+}
+
+impl<T, const N: usize> Fill<'_, T, N> {
+    /// Writes `value` into the next slot, to be published with the batch.
+    ///
+    /// Gives the value back once `max` items have been pushed, or once the batch has used up
+    /// the free slots: the channel is full until the batch is published and read.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.remaining == 0 {
+            return Err(value);
+        }
+
+        let tail = self.tail;
+        let index = self.tx.inner.buffer.index(tail);
+        let slot = self.tx.inner.buffer.get(index);
+
+        // Acquire: synchronize with receiver's Release store.
+        // The slots written by this batch keep their stamp until published: once the ring
+        // wraps around to them, they don't match `tail` and the batch is full
+        if slot.load_stamp() != tail {
+            // Slot not consumed yet
+            return Err(value);
+        }
+
+        unsafe { slot.write(value) };
+
+        // Advance the batch cursor, the stamp is stored on drop
+        self.tail = self.tx.inner.buffer.next(tail);
+        self.remaining -= 1;
+        Ok(())
+    }
+
+    /// Returns `true` if the receiver has dropped.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+
+    /// Returns how many items we're still allowed to push (upper bound).
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.remaining
     }
 }
 
-unsafe impl<T: Send, const N: usize> Sync for Sender<T, N> {}
-unsafe impl<T: Send, const N: usize> Send for Sender<T, N> {}
+impl<T, const N: usize> Drop for Fill<'_, T, N> {
+    fn drop(&mut self) {
+        if self.original_tail == self.tail {
+            return;
+        }
+
+        // Release stamps from the last slot to the first: the receiver only moves past the
+        // first one once it is stored, and then finds the others stored as well
+        let buffer = &self.tx.inner.buffer;
+        let pushed = buffer.distance(self.original_tail, self.tail);
+        for offset in (0..pushed).rev() {
+            let seq = buffer.advance(self.original_tail, offset);
+            buffer.get(buffer.index(seq)).store_stamp(buffer.next(seq));
+        }
+
+        self.tx.tail = self.tail;
+        self.tx
+            .inner
+            .notify_receiver_at(|high| self.tx.holds_at_least(high));
+    }
+}
 
 #[cfg(feature = "async")]
 mod r#async {
     use std::{
         future::Future,
         pin::Pin,
-        task::{Context, Poll, Waker},
+        task::{Context, Poll, ready},
     };
 
+    use futures::Sink;
+
     use super::*;
+    use crate::spsc::{
+        timer::{ThreadTimer, Timer},
+        wait::Spin,
+    };
 
     #[must_use = "futures do nothing unless polled"]
     pub struct SendFuture<'a, T, const N: usize> {
-        sender: &'a Sender<T, N>,
+        sender: &'a mut Sender<T, N>,
         value: Option<T>,
+        spin: Spin,
     }
 
     /// Safe: the struct is not self-referential:
@@ -143,59 +871,325 @@ mod r#async {
     impl<T, const N: usize> Unpin for SendFuture<'_, T, N> {}
 
     impl<'a, T, const N: usize> SendFuture<'a, T, N> {
-        pub fn new(sender: &'a Sender<T, N>, value: T) -> Self {
+        pub fn new(sender: &'a mut Sender<T, N>, value: T) -> Self {
             Self {
                 sender,
                 value: Some(value),
+                spin: Spin::Never,
             }
         }
 
-        fn register_waker(&self, waker: &Waker) {
-            self.sender.inner.register_sender_waker(waker);
-        }
-
-        fn wake_receiver(&self) {
-            self.sender.inner.wake_receiver();
+        /// Spins while the channel is full, before registering the waker and yielding.
+        ///
+        /// Trades CPU time for latency, like [`RecvFuture::with_spin()`](crate::spsc::vyukov::RecvFuture::with_spin).
+        pub fn with_spin(mut self, spin: Spin) -> Self {
+            self.spin = spin;
+            self
         }
     }
 
     impl<'a, T, const N: usize> Future for SendFuture<'a, T, N> {
         type Output = Result<(), TrySendErr<T>>;
         fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-            let value = self.value.take().expect("polled after completion");
+            loop {
+                let value = self.value.take().expect("polled after completion");
 
-            match self.sender.try_send(value) {
-                Ok(()) => {
-                    // Notify the receiver of the new value
-                    self.wake_receiver();
-                    Poll::Ready(Ok(()))
-                }
-                Err(TrySendErr::Disconnected(v)) => {
-                    // No need to notify as the other half is probably dropped
-                    Poll::Ready(Err(TrySendErr::Disconnected(v)))
+                // On success, the receiver is notified of the new value
+                match self.sender.start_send(value) {
+                    Ok(()) => return Poll::Ready(Ok(())),
+                    Err(TrySendErr::Disconnected(v)) => {
+                        // No need to notify as the other half is probably dropped
+                        return Poll::Ready(Err(TrySendErr::Disconnected(v)));
+                    }
+                    Err(TrySendErr::Full(v)) => {
+                        // we put back the value for future polls
+                        self.value = Some(v);
+
+                        // Wait for a free slot (or the disconnection), then try again
+                        let sender = &*self.sender;
+                        if !self.spin.until(|| sender.has_space() || sender.is_closed()) {
+                            let _ = ready!(self.sender.poll_ready(cx));
+                        }
+                    }
                 }
+            }
+        }
+    }
 
-                Err(TrySendErr::Full(v)) => {
-                    // we put back the value for future polls
-                    self.value = Some(v);
+    #[must_use = "futures do nothing unless polled"]
+    pub struct SendTimeout<'a, T, const N: usize, M: Timer = ThreadTimer> {
+        sender: &'a mut Sender<T, N>,
+        value: Option<T>,
+        /// `None` if too far in the future to be represented: waits forever
+        deadline: Option<Instant>,
+        timer: M,
+        /// Started on the first poll that has to wait
+        sleep: Option<M::Sleep>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize, M: Timer> Unpin for SendTimeout<'_, T, N, M> {}
+
+    impl<'a, T, const N: usize> SendTimeout<'a, T, N> {
+        pub fn new(sender: &'a mut Sender<T, N>, value: T, timeout: Duration) -> Self {
+            Self {
+                sender,
+                value: Some(value),
+                deadline: Instant::now().checked_add(timeout),
+                timer: ThreadTimer,
+                sleep: None,
+            }
+        }
+    }
 
-                    // we store the waker for future polls
-                    self.register_waker(cx.waker());
+    impl<'a, T, const N: usize, M: Timer> SendTimeout<'a, T, N, M> {
+        /// Tracks the deadline with `timer`, like
+        /// [`RecvTimeout::with_timer()`](crate::spsc::vyukov::RecvTimeout::with_timer).
+        pub fn with_timer<M2: Timer>(self, timer: M2) -> SendTimeout<'a, T, N, M2> {
+            SendTimeout {
+                sender: self.sender,
+                value: self.value,
+                deadline: self.deadline,
+                timer,
+                sleep: None,
+            }
+        }
+    }
 
-                    // Double-check: see if space became available
-                    let tail = self.sender.tail.get();
-                    let index = self.sender.inner.buffer.index(tail);
-                    let slot = self.sender.inner.buffer.get(index);
-                    let stamp = slot.load_stamp();
+    impl<'a, T, const N: usize, M: Timer> Future for SendTimeout<'a, T, N, M> {
+        type Output = Result<(), SendTimeoutError<T>>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            loop {
+                let value = this.value.take().expect("polled after completion");
 
-                    if stamp == tail {
-                        // Slot is now ready, self-wake
-                        cx.waker().wake_by_ref();
+                // On success, the receiver is notified of the new value
+                match this.sender.start_send(value) {
+                    Ok(()) => return Poll::Ready(Ok(())),
+                    Err(TrySendErr::Disconnected(v)) => {
+                        return Poll::Ready(Err(SendTimeoutError::Disconnected(v)));
                     }
+                    Err(TrySendErr::Full(v)) => {
+                        // we put back the value for future polls
+                        this.value = Some(v);
+
+                        // Wait for a free slot (or the disconnection), then try again
+                        if this.sender.poll_ready(cx).is_ready() {
+                            continue;
+                        }
 
-                    Poll::Pending
+                        let Some(deadline) = this.deadline else {
+                            return Poll::Pending;
+                        };
+                        let timer = &this.timer;
+                        let sleep = this
+                            .sleep
+                            .get_or_insert_with(|| timer.sleep_until(deadline));
+                        ready!(Pin::new(sleep).poll(cx));
+
+                        let value = this.value.take().expect("polled after completion");
+                        return Poll::Ready(Err(SendTimeoutError::Timeout(value)));
+                    }
                 }
             }
         }
     }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct SendOwned<T, const N: usize> {
+        sender: Option<Sender<T, N>>,
+        value: Option<T>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize> Unpin for SendOwned<T, N> {}
+
+    impl<T, const N: usize> SendOwned<T, N> {
+        pub fn new(sender: Sender<T, N>, value: T) -> Self {
+            Self {
+                sender: Some(sender),
+                value: Some(value),
+            }
+        }
+    }
+
+    impl<T, const N: usize> Future for SendOwned<T, N> {
+        type Output = (Sender<T, N>, Result<(), TrySendErr<T>>);
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            let sender = this.sender.as_mut().expect("polled after completion");
+            let result = loop {
+                let value = this.value.take().expect("polled after completion");
+
+                // On success, the receiver is notified of the new value
+                match sender.start_send(value) {
+                    Ok(()) => break Ok(()),
+                    Err(TrySendErr::Disconnected(v)) => break Err(TrySendErr::Disconnected(v)),
+                    Err(TrySendErr::Full(v)) => {
+                        // we put back the value for future polls
+                        this.value = Some(v);
+
+                        // Wait for a free slot (or the disconnection), then try again
+                        let _ = ready!(sender.poll_ready(cx));
+                    }
+                }
+            };
+            // The sender is handed back only once the future completes
+            let sender = this.sender.take().expect("polled after completion");
+            Poll::Ready((sender, result))
+        }
+    }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct ReserveFuture<'a, T, const N: usize> {
+        sender: Option<&'a mut Sender<T, N>>,
+    }
+
+    impl<'a, T, const N: usize> ReserveFuture<'a, T, N> {
+        pub fn new(sender: &'a mut Sender<T, N>) -> Self {
+            Self {
+                sender: Some(sender),
+            }
+        }
+    }
+
+    impl<'a, T, const N: usize> Future for ReserveFuture<'a, T, N> {
+        type Output = Result<Permit<'a, T, N>, SendError>;
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let sender = self.sender.as_mut().expect("polled after completion");
+            ready!(sender.poll_ready(cx))?;
+
+            // The sender is borrowed by the permit from now on
+            let sender = self.sender.take().expect("polled after completion");
+            Poll::Ready(Ok(Permit { sender }))
+        }
+    }
+
+    /// Free slot reserved by [`Sender::reserve_async()`].
+    ///
+    /// The `&mut` borrow of the sender keeps the slot free until [`send`](Self::send) fills it.
+    /// Dropping the permit without sending publishes nothing.
+    #[must_use = "a permit does nothing unless a value is sent through it"]
+    pub struct Permit<'a, T, const N: usize> {
+        sender: &'a mut Sender<T, N>,
+    }
+
+    impl<T, const N: usize> Permit<'_, T, N> {
+        /// Moves `value` into the reserved slot, and notifies the receiver.
+        ///
+        /// If the receiver has dropped since the reservation, the value is dropped
+        /// along with the channel.
+        #[inline]
+        pub fn send(self, value: T) {
+            let tail = self.sender.tail;
+            WriteSlot {
+                tx: &mut *self.sender,
+                tail,
+            }
+            .write(value);
+        }
+    }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct ClosedFuture<'a, T, const N: usize> {
+        sender: &'a mut Sender<T, N>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize> Unpin for ClosedFuture<'_, T, N> {}
+
+    impl<'a, T, const N: usize> ClosedFuture<'a, T, N> {
+        pub fn new(sender: &'a mut Sender<T, N>) -> Self {
+            Self { sender }
+        }
+    }
+
+    impl<T, const N: usize> Future for ClosedFuture<'_, T, N> {
+        type Output = ();
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.sender.is_closed() {
+                return Poll::Ready(());
+            }
+
+            // we store the waker, then check again in case the receiver dropped in the meanwhile
+            self.sender.inner.register_sender_waker(cx.waker());
+            if self.sender.is_closed() {
+                return Poll::Ready(());
+            }
+            Poll::Pending
+        }
+    }
+
+    #[must_use = "futures do nothing unless polled"]
+    pub struct FlushFuture<'a, T, const N: usize> {
+        sender: &'a mut Sender<T, N>,
+    }
+
+    /// Safe: the struct is not self-referential:
+    /// future fields are not pointing to other fields within the same struct
+    impl<T, const N: usize> Unpin for FlushFuture<'_, T, N> {}
+
+    impl<'a, T, const N: usize> FlushFuture<'a, T, N> {
+        pub fn new(sender: &'a mut Sender<T, N>) -> Self {
+            Self { sender }
+        }
+    }
+
+    impl<T, const N: usize> Future for FlushFuture<'_, T, N> {
+        type Output = Result<(), SendError>;
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if let Poll::Ready(result) = self.sender.flushed() {
+                return Poll::Ready(result);
+            }
+
+            // A receiver task waiting below its watermark must consume the last values
+            self.sender.inner.notify_receiver();
+
+            // we store the waker, then check again in case the receiver consumed
+            // the last value (or dropped) in the meanwhile
+            self.sender.inner.register_sender_waker(cx.waker());
+            self.sender.flushed()
+        }
+    }
+
+    /// Values are sent one at a time: [`poll_ready`](Sink::poll_ready) waits for a free slot,
+    /// registering the sender waker while the channel is full, then
+    /// [`start_send`](Sink::start_send) publishes the value and wakes the receiver.
+    ///
+    /// Sent values are visible to the receiver right away, so flushing only wakes a receiver
+    /// task waiting below its [high watermark](crate::spsc::vyukov::Receiver::set_high_watermark).
+    /// Closing the sink disconnects the channel like dropping the sender: the receiver
+    /// gets the values in flight, then [`TryRecvError::Disconnected`](crate::spsc::TryRecvError::Disconnected).
+    impl<T, const N: usize> Sink<T> for Sender<T, N> {
+        type Error = SendError;
+
+        fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+            Sender::poll_ready(self.get_mut(), cx)
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the channel is full, i.e. if [`poll_ready`](Sink::poll_ready) didn't
+        /// return `Poll::Ready(Ok(()))` first.
+        fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), SendError> {
+            match Sender::start_send(self.get_mut(), item) {
+                Ok(()) => Ok(()),
+                Err(TrySendErr::Disconnected(_)) => Err(SendError),
+                Err(TrySendErr::Full(_)) => panic!("start_send called without poll_ready"),
+            }
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+            self.inner.notify_receiver();
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+            self.close();
+            Poll::Ready(Ok(()))
+        }
+    }
 }
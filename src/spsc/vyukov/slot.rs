@@ -1,8 +1,10 @@
-use crate::ring::{RingBuffer, Storable};
-use std::cell::UnsafeCell;
+use crate::ring::{DYNAMIC, RingBuffer, Storable};
+use crate::sync::{
+    UnsafeCell,
+    atomic::{AtomicUsize, Ordering},
+};
 use std::mem::MaybeUninit;
-use std::sync::atomic::AtomicUsize;
-use std::{ptr, sync::atomic::Ordering};
+use std::ptr;
 
 /// A slot in the ring buffer with per-slot sequence stamp for [Vyukov](https://x.com/dvyukov)-style synchronization.
 ///
@@ -14,6 +16,8 @@ pub struct Slot<T> {
     /// - Initial: slot index (0, 1, 2, ..., N-1)
     /// - After write: tail + 1 (signals "data ready for reader")  
     /// - After read: head + N (signals "slot ready for next writer lap")
+    ///
+    /// Additions wrap at `2 * N` when `N` is not a power of 2 (see [`RingBuffer::exact`]).
     pub(crate) stamp: AtomicUsize,
     /// The actual value storage
     value: UnsafeCell<MaybeUninit<T>>,
@@ -40,6 +44,14 @@ impl<T> Slot<T> {
     pub fn store_stamp(&self, stamp: usize) {
         self.stamp.store(stamp, Ordering::Release);
     }
+
+    /// Returns a raw pointer to the value storage, to access it in place.
+    ///
+    /// Dereferencing it requires no concurrent access to this slot's value.
+    #[inline]
+    pub(crate) fn as_ptr(&self) -> *mut MaybeUninit<T> {
+        self.value.get()
+    }
 }
 
 impl<T> Storable for Slot<T> {
@@ -51,7 +63,8 @@ impl<T> Storable for Slot<T> {
     /// - Will overwrite any existing value without dropping it
     #[inline]
     unsafe fn write(&self, value: T) {
-        unsafe { ptr::write((*self.value.get()).as_mut_ptr(), value) };
+        self.value
+            .with_mut(|slot| unsafe { ptr::write((*slot).as_mut_ptr(), value) });
     }
 
     /// Reads the value from the slot.
@@ -61,7 +74,8 @@ impl<T> Storable for Slot<T> {
     /// - Caller must ensure no concurrent access to this slot's value
     #[inline]
     unsafe fn read(&self) -> T {
-        unsafe { ptr::read((*self.value.get()).as_ptr()) }
+        self.value
+            .with(|slot| unsafe { ptr::read((*slot).as_ptr()) })
     }
 
     /// Drops the value in place.
@@ -70,9 +84,8 @@ impl<T> Storable for Slot<T> {
     /// - Caller must ensure the slot contains initialized data
     #[inline]
     unsafe fn drop_in_place(&self) {
-        unsafe {
-            ptr::drop_in_place((*self.value.get()).as_mut_ptr());
-        }
+        self.value
+            .with_mut(|slot| unsafe { ptr::drop_in_place((*slot).as_mut_ptr()) });
     }
 }
 
@@ -83,7 +96,29 @@ impl<T, const N: usize> Default for RingBuffer<Slot<T>, N> {
     }
 }
 
-#[cfg(test)]
+impl<T, const N: usize> RingBuffer<Slot<T>, N> {
+    /// Like [`Default::default`], but accepts any positive `N`.
+    pub(crate) fn default_exact() -> Self {
+        let slots = std::array::from_fn(|i| Slot::new(i));
+        Self::exact(slots)
+    }
+}
+
+impl<T> RingBuffer<Slot<T>, DYNAMIC> {
+    /// Allocates a buffer with `capacity` slots, which must be a power of two.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let slots: Box<[_]> = (0..capacity).map(Slot::new).collect();
+        Self::from(slots)
+    }
+
+    /// Allocates a buffer with any positive number of slots.
+    pub(crate) fn with_exact_capacity(capacity: usize) -> Self {
+        let slots: Box<[_]> = (0..capacity).map(Slot::new).collect();
+        Self::exact_boxed(slots)
+    }
+}
+
+#[cfg(all(test, not(loom)))]
 mod slot_test {
     use crate::ring::RingBuffer;
 
@@ -136,6 +171,11 @@ mod slot_test {
         for i in 0..N {
             assert_eq!(ring.get(i).load_stamp(), i);
         }
+
+        let ring = RingBuffer::<Slot<i32>, DYNAMIC>::with_capacity(N);
+        for i in 0..N {
+            assert_eq!(ring.get(i).load_stamp(), i);
+        }
     }
 
     /// Test stamp update protocol
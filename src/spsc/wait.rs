@@ -0,0 +1,270 @@
+//! Pluggable idle behaviour for the retry loops of the channels.
+//!
+//! [`send_with()`](crate::spsc::lamport::Sender::send_with) and
+//! [`recv_with()`](crate::spsc::lamport::Receiver::recv_with) retry the lock-free operation
+//! until it succeeds, asking a [`WaitStrategy`] what to do between two attempts.
+//! The same pipeline code can then busy-spin on isolated cores and back off on shared machines.
+//!
+//! | Strategy | Idle behaviour |
+//! |----------|----------------|
+//! | [`BusySpin`] | `hint::spin_loop` on every attempt (same as `send_spin`/`recv_spin`) |
+//! | [`Backoff`] | Exponential spinning, then `thread::yield_now` |
+//! | [`SpinThenYield`] | Spins a fixed number of times, then `thread::yield_now` |
+//! | [`SpinThenPark`] | Spins a fixed number of times, then parks the thread |
+//! | [`Sleep`] | `thread::sleep` for a fixed duration |
+//!
+//! Parking relies on the other half unparking the thread: this happens when it makes progress,
+//! whichever the operation, or when it drops.
+//!
+//! The futures yield to the executor instead, once their waker is registered. With a [`Spin`]
+//! policy (see [`RecvFuture::with_spin()`](crate::spsc::lamport::RecvFuture::with_spin)), they
+//! first spin for a while: a consumer on a dedicated runtime thread then gets close to the
+//! latency of `recv_spin`, and still yields when the channel stays idle.
+
+use std::time::Duration;
+
+use crate::sync::{hint, thread};
+
+/// What the channel should do after [`WaitStrategy::wait`] returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wait {
+    /// Try the operation again.
+    Retry,
+    /// Park the thread until the other half makes progress, then try again.
+    Park,
+}
+
+/// Decides how a thread idles while the channel is full (sender) or empty (receiver).
+pub trait WaitStrategy {
+    /// Idles after a failed attempt.
+    fn wait(&mut self) -> Wait;
+
+    /// Restores the initial state. Called at the beginning of every operation.
+    fn reset(&mut self) {}
+}
+
+/// Hints the CPU that it is in a spin-wait on every attempt.
+///
+/// Lowest latency, but keeps the core busy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BusySpin;
+
+impl WaitStrategy for BusySpin {
+    #[inline]
+    fn wait(&mut self) -> Wait {
+        hint::spin_loop();
+        Wait::Retry
+    }
+}
+
+/// Exponential backoff: spins for an exponentially growing number of iterations,
+/// then yields to the scheduler.
+///
+/// See [`crossbeam_utils::Backoff`].
+#[derive(Debug, Default)]
+pub struct Backoff(crossbeam_utils::Backoff);
+
+impl WaitStrategy for Backoff {
+    #[inline]
+    fn wait(&mut self) -> Wait {
+        self.0.snooze();
+        Wait::Retry
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+/// Spins `spins` times, then yields to the scheduler on every following attempt.
+#[derive(Debug, Clone, Copy)]
+pub struct SpinThenYield {
+    spins: u32,
+    step: u32,
+}
+
+impl SpinThenYield {
+    pub const fn new(spins: u32) -> Self {
+        Self { spins, step: 0 }
+    }
+}
+
+impl Default for SpinThenYield {
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+
+impl WaitStrategy for SpinThenYield {
+    #[inline]
+    fn wait(&mut self) -> Wait {
+        if self.step < self.spins {
+            self.step += 1;
+            hint::spin_loop();
+        } else {
+            thread::yield_now();
+        }
+        Wait::Retry
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.step = 0;
+    }
+}
+
+/// Spins `spins` times, then parks the thread until the other half makes progress.
+#[derive(Debug, Clone, Copy)]
+pub struct SpinThenPark {
+    spins: u32,
+    step: u32,
+}
+
+impl SpinThenPark {
+    pub const fn new(spins: u32) -> Self {
+        Self { spins, step: 0 }
+    }
+}
+
+impl Default for SpinThenPark {
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+
+impl WaitStrategy for SpinThenPark {
+    #[inline]
+    fn wait(&mut self) -> Wait {
+        if self.step < self.spins {
+            self.step += 1;
+            hint::spin_loop();
+            Wait::Retry
+        } else {
+            Wait::Park
+        }
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.step = 0;
+    }
+}
+
+/// Sleeps for the given duration on every attempt.
+#[derive(Debug, Clone, Copy)]
+pub struct Sleep(pub Duration);
+
+impl WaitStrategy for Sleep {
+    #[inline]
+    fn wait(&mut self) -> Wait {
+        thread::sleep(self.0);
+        Wait::Retry
+    }
+}
+
+/// How long a future spins on an empty (or full) channel before registering its waker
+/// and yielding to the executor.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Spin {
+    /// Yields right away.
+    #[default]
+    Never,
+    /// Re-checks the channel up to this many times, hinting the CPU that it is in a spin-wait.
+    Iterations(u32),
+    /// Re-checks the channel for up to this long, hinting the CPU that it is in a spin-wait.
+    /// Durations too long to be represented, such as `Duration::MAX`, spin until it is ready.
+    For(Duration),
+}
+
+#[cfg(feature = "async")]
+impl Spin {
+    /// Spins until `ready` returns true, or until the policy gives up.
+    ///
+    /// Returns whether `ready` returned true.
+    #[inline]
+    pub(crate) fn until(self, mut ready: impl FnMut() -> bool) -> bool {
+        match self {
+            Self::Never => false,
+            Self::Iterations(spins) => (0..spins).any(|_| {
+                if ready() {
+                    return true;
+                }
+                hint::spin_loop();
+                false
+            }),
+            Self::For(duration) => {
+                use std::time::Instant;
+
+                // `None` if too far in the future to be represented: spin until ready
+                let deadline = Instant::now().checked_add(duration);
+                loop {
+                    if ready() {
+                        return true;
+                    }
+                    if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                        return false;
+                    }
+                    hint::spin_loop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod wait_test {
+    use super::*;
+
+    /// Spin-based strategies switch behaviour after the configured spins, until reset
+    #[test]
+    fn test_spin_then_park() {
+        let mut strategy = SpinThenPark::new(2);
+        assert_eq!(strategy.wait(), Wait::Retry);
+        assert_eq!(strategy.wait(), Wait::Retry);
+        assert_eq!(strategy.wait(), Wait::Park);
+        assert_eq!(strategy.wait(), Wait::Park);
+
+        strategy.reset();
+        assert_eq!(strategy.wait(), Wait::Retry);
+    }
+
+    /// Non-parking strategies always retry
+    #[test]
+    fn test_retry() {
+        assert_eq!(BusySpin.wait(), Wait::Retry);
+        assert_eq!(Backoff::default().wait(), Wait::Retry);
+        assert_eq!(SpinThenYield::new(0).wait(), Wait::Retry);
+        assert_eq!(Sleep(Duration::from_nanos(1)).wait(), Wait::Retry);
+    }
+
+    /// Spin policies give up after their budget, and stop as soon as the condition holds
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_spin() {
+        let mut checks = 0;
+        assert!(!Spin::Never.until(|| true));
+        assert!(!Spin::Iterations(3).until(|| {
+            checks += 1;
+            false
+        }));
+        assert_eq!(checks, 3);
+
+        checks = 0;
+        assert!(Spin::Iterations(10).until(|| {
+            checks += 1;
+            checks == 2
+        }));
+        assert_eq!(checks, 2);
+
+        assert!(!Spin::For(Duration::from_millis(1)).until(|| false));
+        assert!(Spin::For(Duration::from_secs(60)).until(|| true));
+
+        checks = 0;
+        assert!(Spin::For(Duration::MAX).until(|| {
+            checks += 1;
+            checks == 3
+        }));
+        assert_eq!(checks, 3);
+    }
+}
@@ -0,0 +1,191 @@
+//! Synchronization primitives used by the channels.
+//!
+//! They are the `std` ones, unless the crate is built with `RUSTFLAGS="--cfg loom"`: the
+//! [loom](https://docs.rs/loom) model checker then provides them, and explores every interleaving
+//! of the threads spawned within `loom::model`.
+//!
+//!```text
+//! RUSTFLAGS="--cfg loom" cargo test --release --lib loom
+//!```
+
+#[cfg(not(loom))]
+pub(crate) use std::{hint, sync::Arc, sync::atomic};
+
+#[cfg(loom)]
+pub(crate) use loom::{hint, sync::Arc, sync::atomic};
+
+#[cfg(not(loom))]
+pub(crate) mod thread {
+    pub(crate) use std::thread::{Thread, current, park, park_timeout, sleep, yield_now};
+}
+
+#[cfg(loom)]
+pub(crate) mod thread {
+    use std::time::Duration;
+
+    pub(crate) use loom::thread::{Thread, current, park, yield_now};
+
+    /// Loom has no notion of time: a timed park is a spurious wakeup.
+    pub(crate) fn park_timeout(_: Duration) {
+        yield_now();
+    }
+
+    /// Loom has no notion of time: sleeping yields to the other threads.
+    pub(crate) fn sleep(_: Duration) {
+        yield_now();
+    }
+}
+
+/// [`std::cell::UnsafeCell`] with the closure-based API of `loom::cell::UnsafeCell`,
+/// through which loom tracks the accesses to the value.
+pub(crate) struct UnsafeCell<T>(
+    #[cfg(not(loom))] std::cell::UnsafeCell<T>,
+    #[cfg(loom)] loom::cell::UnsafeCell<T>,
+);
+
+impl<T> UnsafeCell<T> {
+    #[inline]
+    pub(crate) fn new(value: T) -> Self {
+        #[cfg(not(loom))]
+        let cell = std::cell::UnsafeCell::new(value);
+        #[cfg(loom)]
+        let cell = loom::cell::UnsafeCell::new(value);
+        Self(cell)
+    }
+
+    /// Reads the value through a const pointer.
+    #[inline(always)]
+    pub(crate) fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+        #[cfg(not(loom))]
+        return f(self.0.get());
+        #[cfg(loom)]
+        return self.0.with(f);
+    }
+
+    /// Writes the value through a mutable pointer.
+    #[inline(always)]
+    pub(crate) fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+        #[cfg(not(loom))]
+        return f(self.0.get());
+        #[cfg(loom)]
+        return self.0.with_mut(f);
+    }
+
+    /// Returns a pointer to the value that outlives the call, for borrows handed out to users.
+    ///
+    /// Loom only checks the access at the time the pointer is taken.
+    #[inline(always)]
+    pub(crate) fn get(&self) -> *mut T {
+        self.with_mut(|ptr| ptr)
+    }
+}
+
+#[cfg(all(feature = "async", not(loom)))]
+pub(crate) use futures::task::AtomicWaker;
+
+/// `loom::future::AtomicWaker` with the registration API of [`futures::task::AtomicWaker`].
+#[cfg(all(feature = "async", loom))]
+#[derive(Debug, Default)]
+pub(crate) struct AtomicWaker(loom::future::AtomicWaker);
+
+#[cfg(all(feature = "async", loom))]
+impl AtomicWaker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn register(&self, waker: &std::task::Waker) {
+        self.0.register_by_ref(waker);
+    }
+
+    pub(crate) fn wake(&self) {
+        self.0.wake();
+    }
+}
+
+/// Asymmetric memory barriers, for protocols where one side runs much more often than the
+/// other: the frequent side only keeps the compiler from reordering its accesses with
+/// [`light()`], and the rare side pays for both with [`heavy()`], a full fence executed by
+/// every running thread of the process.
+///
+/// On Linux, [`heavy()`] is the `membarrier` system call. Elsewhere, and under loom, heavy
+/// barriers are not [`supported()`]: callers must fall back to `SeqCst` fences on both sides.
+/// They must also fall back for good once a heavy barrier has [`failed()`].
+pub(crate) mod barrier {
+    #[cfg(all(target_os = "linux", not(loom)))]
+    use std::sync::{
+        OnceLock,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    /// Set once a heavy barrier failed, for the whole process.
+    #[cfg(all(target_os = "linux", not(loom)))]
+    static FAILED: AtomicBool = AtomicBool::new(false);
+
+    /// Returns true if [`heavy()`] can be used, registering the process on the first call.
+    #[cfg(all(target_os = "linux", not(loom)))]
+    pub(crate) fn supported() -> bool {
+        static REGISTERED: OnceLock<bool> = OnceLock::new();
+
+        *REGISTERED.get_or_init(|| membarrier(libc::MEMBARRIER_CMD_REGISTER_PRIVATE_EXPEDITED))
+    }
+
+    #[cfg(not(all(target_os = "linux", not(loom))))]
+    pub(crate) fn supported() -> bool {
+        false
+    }
+
+    /// Orders the accesses of the calling thread before and after the call, as seen by a
+    /// thread executing [`heavy()`].
+    #[inline(always)]
+    pub(crate) fn light() {
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Executes a full fence on every running thread of the process: either a [`light()`]
+    /// barrier of another thread precedes it, and the accesses before that barrier are visible
+    /// once it returns, or the barrier follows it, and the accesses after that barrier observe
+    /// everything done before the call.
+    ///
+    /// Only to be called once [`supported()`] returned true. Returns false if the barrier
+    /// failed: nothing is ordered then, and [`failed()`] returns true from now on.
+    #[cfg(all(target_os = "linux", not(loom)))]
+    pub(crate) fn heavy() -> bool {
+        let done = membarrier(libc::MEMBARRIER_CMD_PRIVATE_EXPEDITED);
+        if !done {
+            FAILED.store(true, Ordering::Relaxed);
+        }
+        done
+    }
+
+    #[cfg(not(all(target_os = "linux", not(loom))))]
+    pub(crate) fn heavy() -> bool {
+        unreachable!("heavy barriers are not supported on this platform");
+    }
+
+    /// Returns true once a [`heavy()`] barrier failed in the process.
+    #[cfg(all(target_os = "linux", not(loom)))]
+    #[inline(always)]
+    pub(crate) fn failed() -> bool {
+        FAILED.load(Ordering::Relaxed)
+    }
+
+    #[cfg(not(all(target_os = "linux", not(loom))))]
+    #[inline(always)]
+    pub(crate) fn failed() -> bool {
+        false
+    }
+
+    #[cfg(all(target_os = "linux", not(loom)))]
+    fn membarrier(cmd: libc::c_int) -> bool {
+        // Safe: membarrier takes no pointer, and fails cleanly on unsupported commands
+        unsafe {
+            libc::syscall(
+                libc::SYS_membarrier,
+                cmd,
+                0 as libc::c_uint,
+                0 as libc::c_int,
+            ) == 0
+        }
+    }
+}